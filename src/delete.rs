@@ -0,0 +1,172 @@
+use crate::{Dialect, Select, SqlColumn, SqlTable};
+
+/// Builder for `DELETE` statement
+///
+/// # Example
+///
+/// ```no_run
+/// // delete orphaned details
+/// let sql: String = Delete::new()
+///     .with_table(OrderDetails::as_table())
+///     .with_where_not_in(
+///         OrderDetails::OrderId,
+///         Select::new().with_column(Order::Id).with_table(Order::as_table()),
+///     )
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct Delete {
+    table: String,
+    cond: Vec<Condition>,
+}
+
+/// A deletion condition, subqueries are rendered when building, so they
+/// follow the dialect of the statement
+#[derive(Clone)]
+enum Condition {
+    Sql(String),
+    In {
+        col: SqlColumn,
+        sub: Box<Select>,
+        negated: bool,
+    },
+}
+
+impl Condition {
+    fn render(self, dialect: Dialect) -> String {
+        match self {
+            Self::Sql(sql) => sql,
+            Self::In { col, sub, negated } => {
+                let op = if negated { "NOT IN" } else { "IN" };
+                format!("{col} {op} {}", sub.parenthesized(dialect))
+            }
+        }
+    }
+}
+
+impl Default for Delete {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Delete {
+    /// Create an empty delete
+    pub const fn new() -> Self {
+        Self {
+            table: String::new(),
+            cond: vec![],
+        }
+    }
+
+    /// Sets the table from which the rows will be deleted
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has already been set earlier.
+    pub fn with_table<T: SqlTable>(mut self, table: T) -> Self {
+        self.set_table(table);
+        self
+    }
+
+    /// Adds a deletion condition
+    pub fn with_where_cond<C: Into<String>>(mut self, cond: C) -> Self {
+        self.push_where_cond(cond);
+        self
+    }
+
+    /// Adds a deletion condition `A IN (SELECT ...)`
+    pub fn with_where_in(mut self, col: SqlColumn, sub: Select) -> Self {
+        self.push_where_in(col, sub);
+        self
+    }
+
+    /// Adds a deletion condition `A NOT IN (SELECT ...)`
+    pub fn with_where_not_in(mut self, col: SqlColumn, sub: Select) -> Self {
+        self.push_where_not_in(col, sub);
+        self
+    }
+
+    /// Sets the table from which the rows will be deleted
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has already been set earlier.
+    pub fn set_table<T: SqlTable>(&mut self, _table: T) {
+        #[cfg(debug_assertions)]
+        if !self.table.is_empty() {
+            panic!("table already exists");
+        }
+        self.table = T::TABLE_NAME.into();
+    }
+
+    /// Adds a deletion condition
+    pub fn push_where_cond<C: Into<String>>(&mut self, cond: C) {
+        self.cond.push(Condition::Sql(cond.into()));
+    }
+
+    /// Adds a deletion condition `A IN (SELECT ...)`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut delete = Delete::new();
+    /// delete.set_table(User::as_table());
+    /// delete.push_where_in(
+    ///     User::DataId,
+    ///     Select::new()
+    ///         .with_column(UserData::Id)
+    ///         .with_table(UserData::as_table())
+    ///         .with_where_cond(UserData::Expired.eq(true)),
+    /// );
+    /// assert_eq!(
+    ///     delete.build(),
+    ///     r#"DELETE FROM "User" WHERE ("User"."data_id" IN (SELECT "UserData"."id" FROM "UserData" WHERE ("UserData"."expired" = 1)))"#
+    /// );
+    /// ```
+    pub fn push_where_in(&mut self, col: SqlColumn, sub: Select) {
+        self.cond.push(Condition::In {
+            col,
+            sub: Box::new(sub),
+            negated: false,
+        });
+    }
+
+    /// Adds a deletion condition `A NOT IN (SELECT ...)`
+    pub fn push_where_not_in(&mut self, col: SqlColumn, sub: Select) {
+        self.cond.push(Condition::In {
+            col,
+            sub: Box::new(sub),
+            negated: true,
+        });
+    }
+
+    /// Performs query building by consuming itself for the default
+    /// [`Dialect`] (SQLite), see [`Select::build`]
+    ///
+    /// Note that without conditions all rows of the table are deleted.
+    pub fn build(self) -> String {
+        self.build_for(Dialect::default())
+    }
+
+    /// Performs query building by consuming itself for the specified dialect,
+    /// see [`Select::build_for`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sql = Delete::new()
+    ///     .with_table(User::as_table())
+    ///     .with_where_cond(User::Name.eq("Bob"))
+    ///     .build_for(Dialect::MySql);
+    /// assert_eq!(sql, "DELETE FROM `User` WHERE (`User`.`name` = 'Bob')");
+    /// ```
+    pub fn build_for(self, dialect: Dialect) -> String {
+        let mut sql = format!("DELETE FROM {}", self.table);
+        if !self.cond.is_empty() {
+            sql += " WHERE ";
+            sql += &crate::join_and(self.cond.into_iter().map(|cond| cond.render(dialect)));
+        }
+        crate::dialect::localize(&sql, dialect)
+    }
+}
@@ -0,0 +1,153 @@
+/// The DBMS flavor used to render the parts of queries that differ between
+/// database engines
+///
+/// Queries are composed in a common form: identifiers in double quotes,
+/// literals in single quotes and `true`/`false` for booleans. It is adapted
+/// to the dialect when the query is built, see
+/// [`Select::build_for`](crate::Select::build_for).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+    #[default]
+    Sqlite,
+    Postgres,
+    MySql,
+}
+
+impl Dialect {
+    /// Returns the dialect of the sqlx database driver
+    #[cfg(feature = "sqlx")]
+    pub(crate) fn of<DB: sqlx::Database>() -> Self {
+        match DB::NAME {
+            "PostgreSQL" => Self::Postgres,
+            "MySQL" => Self::MySql,
+            _ => Self::Sqlite,
+        }
+    }
+
+    /// Returns the placeholder for a bound parameter with the specified
+    /// 1-based index: `$N` for Postgres and `?` for the others
+    pub fn placeholder(self, index: usize) -> String {
+        match self {
+            Self::Postgres => format!("${index}"),
+            Self::Sqlite | Self::MySql => String::from("?"),
+        }
+    }
+}
+
+/// Adapts a query rendered in the common form (`"identifiers"`,
+/// `'literals'`, `true`/`false`) to the specified dialect:
+/// - SQLite: booleans become `1`/`0`
+/// - Postgres: booleans become `TRUE`/`FALSE`
+/// - MySQL: booleans become `TRUE`/`FALSE`, identifiers are quoted with
+///   backticks and backslashes in literals are escaped
+pub(crate) fn localize(sql: &str, dialect: Dialect) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    if c == '\\' && dialect == Dialect::MySql {
+                        out.push(c);
+                    }
+                    out.push(c);
+                    if c == '\'' {
+                        // a doubled quote is part of the literal
+                        if chars.peek() != Some(&'\'') {
+                            break;
+                        }
+                        out.extend(chars.next());
+                    }
+                }
+            }
+            '"' => {
+                let quote = if dialect == Dialect::MySql { '`' } else { '"' };
+                out.push(quote);
+                while let Some(c) = chars.next() {
+                    if c == '"' {
+                        if chars.peek() != Some(&'"') {
+                            break;
+                        }
+                        chars.next();
+                    }
+                    if c == quote {
+                        out.push(c);
+                    }
+                    out.push(c);
+                }
+                out.push(quote);
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '$' => {
+                let mut word = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_alphanumeric() && c != '_' && c != '$' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                let value = match word.as_str() {
+                    "true" => true,
+                    "false" => false,
+                    _ => {
+                        out += &word;
+                        continue;
+                    }
+                };
+                out += match (dialect, value) {
+                    (Dialect::Sqlite, true) => "1",
+                    (Dialect::Sqlite, false) => "0",
+                    (Dialect::Postgres | Dialect::MySql, true) => "TRUE",
+                    (Dialect::Postgres | Dialect::MySql, false) => "FALSE",
+                };
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Where `NULL` values are placed by `asc()`/`desc()` orderings of a
+/// [`Select`](crate::Select), see [`Select::with_nulls_order`](crate::Select::with_nulls_order)
+///
+/// Engines disagree on it by default: SQLite and MySQL treat `NULL` as the
+/// smallest value, while Postgres treats it as the largest. Choosing
+/// `Smallest` or `Largest` makes the ordering explicit (`NULLS FIRST` /
+/// `NULLS LAST`, emulated for MySQL) when the query is built, so it's the
+/// same on every engine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NullsOrder {
+    /// Leave it to the engine (nothing is added to the ordering)
+    #[default]
+    Unspecified,
+    /// `NULL` goes first for `ASC` and last for `DESC`
+    Smallest,
+    /// `NULL` goes last for `ASC` and first for `DESC`
+    Largest,
+}
+
+impl NullsOrder {
+    /// Produces `{expr} ASC|DESC` with explicit placement of `NULL` for the
+    /// specified dialect
+    pub(crate) fn apply(self, expr: &str, desc: bool, dialect: Dialect) -> String {
+        let dir = if desc { "DESC" } else { "ASC" };
+        // for `DESC` the engine's own placement of NULL is reversed
+        let nulls_first = match self {
+            Self::Unspecified => return format!("{expr} {dir}"),
+            Self::Smallest => !desc,
+            Self::Largest => desc,
+        };
+        match dialect {
+            Dialect::Sqlite | Dialect::Postgres => {
+                let nulls = if nulls_first { "FIRST" } else { "LAST" };
+                format!("{expr} {dir} NULLS {nulls}")
+            }
+            // MySQL doesn't support `NULLS FIRST/LAST`, sort by `IS NULL` first
+            Dialect::MySql => {
+                let nulls = if nulls_first { "DESC" } else { "ASC" };
+                format!("{expr} IS NULL {nulls}, {expr} {dir}")
+            }
+        }
+    }
+}
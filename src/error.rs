@@ -0,0 +1,100 @@
+use crate::SqlTable;
+
+/// An error from the database driver (for example, a failure to decode a
+/// row) with the table and, when known, the column it happened on
+///
+/// # Example
+///
+/// ```no_run
+/// let user = sqlx::query_as::<_, User>(&sql)
+///     .fetch_one(&pool)
+///     .await
+///     .map_err(HumbleError::new::<User, _>)?;
+/// ```
+#[derive(Debug)]
+pub struct HumbleError {
+    table: &'static str,
+    column: Option<&'static str>,
+    source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl std::fmt::Display for HumbleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "table `{}`", self.table)?;
+        if let Some(column) = self.column {
+            write!(f, ", column `{column}`")?;
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl std::error::Error for HumbleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl HumbleError {
+    /// Wraps an error that happened on the table `T`
+    pub fn new<T, E>(source: E) -> Self
+    where
+        T: SqlTable,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self {
+            table: crate::sql_column::trim_quotes(T::TABLE_NAME),
+            column: None,
+            source: source.into(),
+        }
+    }
+
+    /// Specifies the column the error happened on
+    pub fn with_column(mut self, column: &'static str) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Returns name of the table
+    pub fn table(&self) -> &'static str {
+        self.table
+    }
+
+    /// Returns name of the column, if it is known
+    pub fn column(&self) -> Option<&'static str> {
+        self.column
+    }
+}
+
+/// A mistake in the query detected by [`Select::try_build`](crate::Select::try_build)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectError {
+    /// A column is selected along with aggregates, but it is not in
+    /// `GROUP BY`
+    MissingGroupBy { column: String },
+    /// A table is joined without an `ON` condition, use a `CROSS JOIN` if
+    /// this is intended
+    PossibleCartesianProduct { table: &'static str },
+    /// A column is selected from a table that is neither selected nor joined
+    UnknownColumn { column: String, table: &'static str },
+}
+
+impl std::fmt::Display for SelectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingGroupBy { column } => {
+                write!(
+                    f,
+                    "column {column} must appear in GROUP BY or be aggregated"
+                )
+            }
+            Self::PossibleCartesianProduct { table } => {
+                write!(f, "table `{table}` is joined without ON condition")
+            }
+            Self::UnknownColumn { column, table } => {
+                write!(f, "column {column} is selected, but table `{table}` is not")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SelectError {}
@@ -0,0 +1,187 @@
+use crate::{OrderBy, Over, Projection, SqlValue};
+
+/// An SQL expression, such as an aggregate, that can be compared with values
+/// or other expressions (for example in `HAVING`)
+///
+/// # Example
+///
+/// ```no_run
+/// let cond = User::Id.count().gt(User::Active.sum());
+/// assert_eq!(cond, r#"COUNT("User"."id") > SUM("User"."active")"#)
+/// ```
+///
+/// Arithmetic operators on columns and expressions produce parenthesized
+/// expressions, so they can be safely used on either side of a comparison:
+///
+/// ```no_run
+/// let cond = Order::Total.gt(Order::Discount * 2);
+/// assert_eq!(cond, r#""Order"."total" > ("Order"."discount" * 2)"#)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expr {
+    sql: String,
+    aggregate: bool,
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.sql)
+    }
+}
+
+impl From<Expr> for String {
+    fn from(value: Expr) -> Self {
+        value.sql
+    }
+}
+
+impl SqlValue for Expr {
+    fn to_sql(&self) -> String {
+        self.sql.clone()
+    }
+}
+
+/// Produces `(A + B)`
+impl<V: SqlValue> std::ops::Add<V> for Expr {
+    type Output = Expr;
+
+    fn add(self, value: V) -> Expr {
+        Expr {
+            sql: format!("({self} + {})", value.to_sql()),
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+/// Produces `(A - B)`
+impl<V: SqlValue> std::ops::Sub<V> for Expr {
+    type Output = Expr;
+
+    fn sub(self, value: V) -> Expr {
+        Expr {
+            sql: format!("({self} - {})", value.to_sql()),
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+/// Produces `(A * B)`
+impl<V: SqlValue> std::ops::Mul<V> for Expr {
+    type Output = Expr;
+
+    fn mul(self, value: V) -> Expr {
+        Expr {
+            sql: format!("({self} * {})", value.to_sql()),
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+/// Produces `(A / B)`
+impl<V: SqlValue> std::ops::Div<V> for Expr {
+    type Output = Expr;
+
+    fn div(self, value: V) -> Expr {
+        Expr {
+            sql: format!("({self} / {})", value.to_sql()),
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+/// Produces `(A % B)`
+impl<V: SqlValue> std::ops::Rem<V> for Expr {
+    type Output = Expr;
+
+    fn rem(self, value: V) -> Expr {
+        Expr {
+            sql: format!("({self} % {})", value.to_sql()),
+            aggregate: self.aggregate,
+        }
+    }
+}
+
+impl Expr {
+    /// Creates an expression from a raw SQL string
+    ///
+    /// The string is used as is, so it must be a valid SQL expression.
+    pub fn new<S: Into<String>>(sql: S) -> Self {
+        Self {
+            sql: sql.into(),
+            aggregate: false,
+        }
+    }
+
+    /// Creates an aggregate expression (like `COUNT(...)`) from a raw SQL
+    /// string
+    ///
+    /// The string is used as is, so it must be a valid SQL expression.
+    pub fn aggregate<S: Into<String>>(sql: S) -> Self {
+        Self {
+            sql: sql.into(),
+            aggregate: true,
+        }
+    }
+
+    /// Returns `true` if the expression is an aggregate
+    pub fn is_aggregate(&self) -> bool {
+        self.aggregate
+    }
+
+    /// Produces an alias: `{expr} AS {alias}`, which remains an aggregate
+    /// if the expression is one
+    pub fn alias(self, alias: &str) -> Projection {
+        Projection::from(self).with_alias(alias)
+    }
+
+    /// Produces a window function call: `{expr} OVER (...)`
+    pub fn over(self, over: Over) -> Expr {
+        Expr::new(format!("{self} {over}"))
+    }
+
+    /// Produces `{expr} ASC` (for ordering)
+    ///
+    /// Explicit placement of `NULL` can be added when building, see
+    /// [`Select::with_nulls_order`](crate::Select::with_nulls_order).
+    pub fn asc(self) -> OrderBy {
+        OrderBy::new(self.to_string(), false)
+    }
+
+    /// Produces `{expr} DESC` (for ordering)
+    ///
+    /// Explicit placement of `NULL` can be added when building, see
+    /// [`Select::with_nulls_order`](crate::Select::with_nulls_order).
+    pub fn desc(self) -> OrderBy {
+        OrderBy::new(self.to_string(), true)
+    }
+
+    /// Produces `A = B`
+    pub fn eq<V: SqlValue>(self, value: V) -> String {
+        format!("{self} = {}", value.to_sql())
+    }
+
+    /// Produces `A != B`
+    pub fn ne<V: SqlValue>(self, value: V) -> String {
+        format!("{self} != {}", value.to_sql())
+    }
+
+    /// Produces `A > B`
+    pub fn gt<V: SqlValue>(self, value: V) -> String {
+        format!("{self} > {}", value.to_sql())
+    }
+
+    /// Produces `A >= B`
+    pub fn ge<V: SqlValue>(self, value: V) -> String {
+        format!("{self} >= {}", value.to_sql())
+    }
+
+    /// Produces `A < B`
+    pub fn lt<V: SqlValue>(self, value: V) -> String {
+        format!("{self} < {}", value.to_sql())
+    }
+
+    /// Produces `A <= B`
+    pub fn le<V: SqlValue>(self, value: V) -> String {
+        format!("{self} <= {}", value.to_sql())
+    }
+}
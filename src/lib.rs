@@ -1,14 +1,26 @@
 #![doc = include_str!("../README.md")]
 
+mod delete;
+mod dialect;
+mod error;
+mod expr;
 mod select;
 mod sql_column;
+mod sql_interval;
 mod sql_table;
 mod sql_value;
+mod window;
 
-pub use select::Select;
+pub use delete::Delete;
+pub use dialect::{Dialect, NullsOrder};
+pub use error::{HumbleError, SelectError};
+pub use expr::Expr;
+pub use select::{FrozenSelect, OrderBy, Projection, Select};
 pub use sql_column::SqlColumn;
+pub use sql_interval::{IntervalUnit, SqlInterval};
 pub use sql_table::SqlTable;
 pub use sql_value::SqlValue;
+pub use window::{Frame, FrameBound, Over};
 
 pub use humble_orm_macro::*;
 
@@ -18,7 +30,7 @@ pub use humble_orm_macro::*;
 ///
 /// ```no_run
 /// let cond = join_and([User::Name.eq("John"), User::Age.gt(30)]);
-/// assert_eq!(cond, r#"("User"."name" = "John") AND ("User"."age" > 30)"#)
+/// assert_eq!(cond, r#"("User"."name" = 'John') AND ("User"."age" > 30)"#)
 /// ```
 pub fn join_and<I>(cond: I) -> String
 where
@@ -36,7 +48,7 @@ where
 ///
 /// ```no_run
 /// let cond = join_or([User::Name.eq("John"), User::Name.eq("Jack")]);
-/// assert_eq!(cond, r#"("User"."name" = "John") OR ("User"."name" = "Jack")"#)
+/// assert_eq!(cond, r#"("User"."name" = 'John') OR ("User"."name" = 'Jack')"#)
 /// ```
 pub fn join_or<I>(cond: I) -> String
 where
@@ -48,15 +60,41 @@ where
         .join(" OR ")
 }
 
+/// produces `COUNT(*)`, which counts all rows including those with `NULL`
+/// values, unlike `COUNT({field})` (see [`SqlColumn::count`])
+///
+/// # Example
+///
+/// ```no_run
+/// let cond = count_star().gt(User::DataId.count());
+/// assert_eq!(cond, r#"COUNT(*) > COUNT("User"."data_id")"#)
+/// ```
+pub fn count_star() -> Expr {
+    Expr::aggregate("COUNT(*)")
+}
+
+/// produces `COUNT(*) AS {alias}`
+///
+/// # Example
+///
+/// ```no_run
+/// let expr = count_star_as("total");
+/// assert_eq!(expr.to_string(), r#"COUNT(*) AS "total""#)
+/// ```
+pub fn count_star_as(alias: &str) -> Projection {
+    count_star().alias(alias)
+}
+
 /// produces `COALESCE({exprs})`
 ///
 /// # Example
 ///
 /// ```no_run
-/// let expr = coalesce([User::FirstName, User::LastName])
-/// assert_eq!(expr, r#"COALESCE("User"."first_name", "User"."last_name")"#)
+/// let expr = coalesce([User::FirstName, User::LastName]);
+/// assert_eq!(expr.to_string(), r#"COALESCE("User"."first_name", "User"."last_name")"#);
+/// let order = coalesce([User::LastLogin, User::CreatedAt]).desc();
 /// ```
-pub fn coalesce<I>(exprs: I) -> String
+pub fn coalesce<I>(exprs: I) -> Expr
 where
     I: IntoIterator,
     I::Item: Into<String>,
@@ -66,7 +104,7 @@ where
         .map(|c| c.into())
         .collect::<Vec<_>>()
         .join(", ");
-    format!("COALESCE({exprs})")
+    Expr::new(format!("COALESCE({exprs})"))
 }
 
 /// produces `COALESCE({exprs}) AS {alias}`
@@ -84,3 +122,195 @@ where
 {
     format!("{} AS {alias:?}", coalesce(exprs))
 }
+
+/// produces a JSON object from `(key, expr)` pairs for the specified
+/// [`Dialect`]: `json_build_object('key', expr, ...)` for Postgres,
+/// `JSON_OBJECT(...)` for MySQL and `json_object(...)` for SQLite
+///
+/// # Example
+///
+/// ```no_run
+/// let expr = json_object([("id", User::Id), ("name", User::Name)], Dialect::Postgres);
+/// assert_eq!(expr, r#"json_build_object('id', "User"."id", 'name', "User"."name")"#)
+/// ```
+pub fn json_object<'a, I, V>(pairs: I, dialect: Dialect) -> String
+where
+    I: IntoIterator<Item = (&'a str, V)>,
+    V: Into<String>,
+{
+    let pairs = pairs
+        .into_iter()
+        .map(|(key, expr)| format!("{}, {}", sql_value::quote_literal(key), expr.into()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let func = match dialect {
+        Dialect::Sqlite => "json_object",
+        Dialect::Postgres => "json_build_object",
+        Dialect::MySql => "JSON_OBJECT",
+    };
+    format!("{func}({pairs})")
+}
+
+/// produces a JSON object (see [`json_object`]) with an alias:
+/// `json_build_object(...) AS {alias}`
+///
+/// # Example
+///
+/// ```no_run
+/// let expr = json_object_as("user", [("id", User::Id), ("name", User::Name)], Dialect::Postgres);
+/// assert_eq!(expr, r#"json_build_object('id', "User"."id", 'name', "User"."name") AS "user""#)
+/// ```
+pub fn json_object_as<'a, I, V>(alias: &str, pairs: I, dialect: Dialect) -> String
+where
+    I: IntoIterator<Item = (&'a str, V)>,
+    V: Into<String>,
+{
+    format!("{} AS {alias:?}", json_object(pairs, dialect))
+}
+
+/// produces the random ordering function for the specified [`Dialect`]:
+/// `RANDOM()` for SQLite and Postgres, `RAND()` for MySQL
+///
+/// # Example
+///
+/// ```no_run
+/// let sql = Select::new()
+///     .with_column(User::Name)
+///     .with_table(User::as_table())
+///     .with_order(random_order(Dialect::Sqlite))
+///     .with_limit(1)
+///     .build();
+/// assert_eq!(sql, r#"SELECT "User"."name" FROM "User" ORDER BY RANDOM() LIMIT 1"#)
+/// ```
+pub fn random_order(dialect: Dialect) -> String {
+    match dialect {
+        Dialect::Sqlite | Dialect::Postgres => String::from("RANDOM()"),
+        Dialect::MySql => String::from("RAND()"),
+    }
+}
+
+/// produces a row comparison `(A, B) > (x, y)` for keyset pagination over a
+/// composite sort key
+///
+/// # Example
+///
+/// ```no_run
+/// let last = (30, 17);
+/// let cond = keyset_after(&[User::Age, User::Id], &[&last.0, &last.1]);
+/// assert_eq!(cond, r#"("User"."age", "User"."id") > (30, 17)"#)
+/// ```
+///
+/// # Panic
+///
+/// Panics if there are no columns or the number of columns and values
+/// differs, since the condition would be invalid SQL.
+pub fn keyset_after(cols: &[SqlColumn], values: &[&dyn SqlValue]) -> String {
+    assert!(!cols.is_empty(), "keyset columns must not be empty");
+    assert_eq!(
+        cols.len(),
+        values.len(),
+        "keyset columns and values must have the same length"
+    );
+    let cols = cols
+        .iter()
+        .map(|col| col.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let values = values
+        .iter()
+        .map(|val| val.to_sql())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({cols}) > ({values})")
+}
+
+/// produces a row constructor `(A, B, C)` from a list of values
+///
+/// # Example
+///
+/// ```no_run
+/// let row = values_row([&1 as &dyn SqlValue, &"a", &true]);
+/// assert_eq!(row, "(1, 'a', true)")
+/// ```
+pub fn values_row<I>(values: I) -> String
+where
+    I: IntoIterator,
+    I::Item: SqlValue,
+{
+    let values = values
+        .into_iter()
+        .map(|val| val.to_sql())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({values})")
+}
+
+/// produces an explicit row constructor `ROW(A, B, C)` (Postgres)
+///
+/// # Example
+///
+/// ```no_run
+/// let row = row([&1 as &dyn SqlValue, &"a"]);
+/// assert_eq!(row, "ROW(1, 'a')")
+/// ```
+pub fn row<I>(values: I) -> String
+where
+    I: IntoIterator,
+    I::Item: SqlValue,
+{
+    format!("ROW{}", values_row(values))
+}
+
+/// produces the count of distinct combinations of the columns for the
+/// specified [`Dialect`]: `COUNT(DISTINCT A, B)` for MySQL and
+/// `COUNT(DISTINCT (A, B))` for Postgres
+///
+/// # Example
+///
+/// ```no_run
+/// let expr = count_distinct_multi([User::FirstName, User::LastName], Dialect::Postgres);
+/// assert_eq!(expr.to_string(), r#"COUNT(DISTINCT ("User"."first_name", "User"."last_name"))"#)
+/// ```
+///
+/// # Panic
+///
+/// Panics for SQLite, which doesn't support counting distinct rows of
+/// several columns.
+pub fn count_distinct_multi<I>(cols: I, dialect: Dialect) -> Expr
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    let cols = cols
+        .into_iter()
+        .map(|c| c.into())
+        .collect::<Vec<_>>()
+        .join(", ");
+    match dialect {
+        Dialect::MySql => Expr::aggregate(format!("COUNT(DISTINCT {cols})")),
+        Dialect::Postgres => Expr::aggregate(format!("COUNT(DISTINCT ({cols}))")),
+        Dialect::Sqlite => panic!("SQLite doesn't support COUNT(DISTINCT) of several columns"),
+    }
+}
+
+/// runs a hand-written query and maps the rows into `T`, an escape hatch for
+/// queries the builder can't express
+///
+/// The query is sent as is, so the caller is responsible for keeping it free
+/// of injected input (bind the values instead of formatting them in).
+///
+/// # Example
+///
+/// ```no_run
+/// let users: Vec<User> = from_sql(&pool, r#"SELECT * FROM "User" WHERE "id" % 2 = 0"#).await?;
+/// ```
+#[cfg(feature = "sqlx")]
+pub async fn from_sql<'e, T, E, DB>(executor: E, sql: &str) -> Result<Vec<T>, sqlx::Error>
+where
+    T: SqlTable + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+    E: 'e + sqlx::Executor<'e, Database = DB>,
+    DB: sqlx::Database,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+{
+    sqlx::query_as::<DB, T>(sql).fetch_all(executor).await
+}
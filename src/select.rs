@@ -1,4 +1,7 @@
-use crate::SqlTable;
+use std::sync::Arc;
+
+use crate::sql_column::trim_quotes;
+use crate::{Dialect, Expr, NullsOrder, SelectError, SqlColumn, SqlTable};
 
 fn format_cond<I>(cond: I) -> String
 where
@@ -10,6 +13,155 @@ where
         .join(" AND ")
 }
 
+/// An entry of the selection list that remembers whether it is a plain
+/// column or an aggregate, which is used by [`Select::try_build`]
+///
+/// It is created implicitly from a [`SqlColumn`], an [`Expr`] or any SQL
+/// string, or explicitly by the aliasing methods like
+/// [`SqlColumn::count_as`], which keep the aggregate flag.
+#[derive(Clone)]
+pub struct Projection {
+    sql: String,
+    column: Option<SqlColumn>,
+    aggregate: bool,
+}
+
+impl std::fmt::Display for Projection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.sql)
+    }
+}
+
+impl From<Projection> for String {
+    fn from(value: Projection) -> Self {
+        value.sql
+    }
+}
+
+impl From<SqlColumn> for Projection {
+    fn from(value: SqlColumn) -> Self {
+        Self {
+            sql: value.to_string(),
+            column: Some(value),
+            aggregate: false,
+        }
+    }
+}
+
+impl From<Expr> for Projection {
+    fn from(value: Expr) -> Self {
+        Self {
+            aggregate: value.is_aggregate(),
+            sql: value.into(),
+            column: None,
+        }
+    }
+}
+
+impl From<String> for Projection {
+    fn from(value: String) -> Self {
+        Self {
+            sql: value,
+            column: None,
+            aggregate: false,
+        }
+    }
+}
+
+impl From<&String> for Projection {
+    fn from(value: &String) -> Self {
+        value.clone().into()
+    }
+}
+
+impl From<&str> for Projection {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl Projection {
+    /// Adds an alias: `{projection} AS {alias}`
+    pub(crate) fn with_alias(mut self, alias: &str) -> Self {
+        // use write to eliminate unnecessary allocations
+        use std::fmt::Write;
+        write!(self.sql, " AS {alias:?}").unwrap();
+        self
+    }
+}
+
+/// An entry of `ORDER BY` that remembers its direction, so the placement of
+/// `NULL` can be made explicit when building (see [`NullsOrder`])
+///
+/// It is created by `asc()`/`desc()` of a column or an expression, or
+/// implicitly from any SQL string, which is used as is.
+#[derive(Clone)]
+pub struct OrderBy {
+    expr: String,
+    desc: Option<bool>,
+}
+
+impl std::fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(NullsOrder::Unspecified, Dialect::default()))
+    }
+}
+
+impl From<OrderBy> for String {
+    fn from(value: OrderBy) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<SqlColumn> for OrderBy {
+    fn from(value: SqlColumn) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<Expr> for OrderBy {
+    fn from(value: Expr) -> Self {
+        String::from(value).into()
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> Self {
+        Self {
+            expr: value,
+            desc: None,
+        }
+    }
+}
+
+impl From<&String> for OrderBy {
+    fn from(value: &String) -> Self {
+        value.clone().into()
+    }
+}
+
+impl From<&str> for OrderBy {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl OrderBy {
+    pub(crate) fn new(expr: String, desc: bool) -> Self {
+        Self {
+            expr,
+            desc: Some(desc),
+        }
+    }
+
+    fn render(&self, nulls: NullsOrder, dialect: Dialect) -> String {
+        match self.desc {
+            Some(desc) => nulls.apply(&self.expr, desc, dialect),
+            None => self.expr.clone(),
+        }
+    }
+}
+
 /// Builder for `SELECT` statement
 ///
 /// # Example
@@ -31,12 +183,17 @@ where
 /// ```
 #[derive(Clone)]
 pub struct Select {
-    columns: String,
+    columns: Vec<Projection>,
     table: String,
+    tables: Vec<&'static str>,
+    join_without_on: Option<&'static str>,
+    sample: Option<f64>,
+    joins: String,
     cond: Vec<String>,
-    group_by: String,
+    group_by: Vec<String>,
     having: Vec<String>,
-    order_by: String,
+    order_by: Vec<OrderBy>,
+    nulls_order: NullsOrder,
     limit: Option<u32>,
     offset: Option<u32>,
 }
@@ -45,19 +202,24 @@ impl Select {
     /// Create an empty select
     pub const fn new() -> Self {
         Self {
-            columns: String::new(),
+            columns: vec![],
             table: String::new(),
+            tables: vec![],
+            join_without_on: None,
+            sample: None,
+            joins: String::new(),
             cond: vec![],
-            group_by: String::new(),
+            group_by: vec![],
             having: vec![],
-            order_by: String::new(),
+            order_by: vec![],
+            nulls_order: NullsOrder::Unspecified,
             limit: None,
             offset: None,
         }
     }
 
     /// Adds a column to be selected
-    pub fn with_column<T: Into<String>>(mut self, col: T) -> Self {
+    pub fn with_column<T: Into<Projection>>(mut self, col: T) -> Self {
         self.push_column(col);
         self
     }
@@ -66,12 +228,22 @@ impl Select {
     pub fn with_columns<I>(mut self, cols: I) -> Self
     where
         I: IntoIterator,
-        I::Item: Into<String>,
+        I::Item: Into<Projection>,
     {
         self.push_columns(cols);
         self
     }
 
+    /// Adds all columns of the table `T` except the specified ones
+    ///
+    /// # Panic
+    ///
+    /// Panics if an excluded name is not a column of the table.
+    pub fn with_columns_except<T: SqlTable>(mut self, exclude: &[&str]) -> Self {
+        self.push_columns_except::<T>(exclude);
+        self
+    }
+
     /// Sets the table from which the selection will be made
     ///
     /// # Panic
@@ -82,6 +254,17 @@ impl Select {
         self
     }
 
+    /// Sets the table from which the selection will be made, excluding the
+    /// tables inheriting it: `FROM ONLY {table}` (Postgres)
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has already been set earlier. Use joins in this case.
+    pub fn with_table_only<T: SqlTable>(mut self, table: T) -> Self {
+        self.set_table_only(table);
+        self
+    }
+
     /// Joins the table for the selection
     ///
     /// # Panic
@@ -124,6 +307,28 @@ impl Select {
         self
     }
 
+    /// Selects only a sample of the table rows, given as a percentage:
+    /// `TABLESAMPLE SYSTEM ({percent})` (Postgres)
+    ///
+    /// # Panic
+    ///
+    /// Panics if the percentage is not in range `0..=100`.
+    pub fn with_sample(mut self, percent: f64) -> Self {
+        self.set_sample(percent);
+        self
+    }
+
+    /// Joins every row of the table to every row of the selection:
+    /// `CROSS JOIN {table}`
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has not been set. First set the table as a starting point.
+    pub fn with_cross_join<T: SqlTable>(mut self, table: T) -> Self {
+        self.cross_join(table);
+        self
+    }
+
     /// Adds a selection condition
     pub fn with_where_cond<C: Into<String>>(mut self, cond: C) -> Self {
         self.push_where_cond(cond);
@@ -131,11 +336,17 @@ impl Select {
     }
 
     /// Adds a column to sort the selection
-    pub fn with_order<O: Into<String>>(mut self, order: O) -> Self {
+    pub fn with_order<O: Into<OrderBy>>(mut self, order: O) -> Self {
         self.push_order(order);
         self
     }
 
+    /// Sets the placement of `NULL` for the `asc()`/`desc()` orderings
+    pub fn with_nulls_order(mut self, nulls: NullsOrder) -> Self {
+        self.set_nulls_order(nulls);
+        self
+    }
+
     /// Adds a column to group the selection.
     pub fn with_group<G: Into<String>>(mut self, group: G) -> Self {
         self.push_group(group);
@@ -161,24 +372,81 @@ impl Select {
     }
 
     /// Adds a column to be selected
-    pub fn push_column<T: Into<String>>(&mut self, col: T) {
-        if !self.columns.is_empty() {
-            self.columns.push(',');
-        }
-        self.columns += &col.into();
+    pub fn push_column<T: Into<Projection>>(&mut self, col: T) {
+        self.columns.push(col.into());
     }
 
     /// Adds columns to be selected
     pub fn push_columns<I>(&mut self, cols: I)
     where
         I: IntoIterator,
-        I::Item: Into<String>,
+        I::Item: Into<Projection>,
     {
         for col in cols {
             self.push_column(col);
         }
     }
 
+    /// Adds all columns of the table `T` except the specified ones
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut select = Select::new();
+    /// select.push_columns_except::<User>(&["data_id"]);
+    /// select.set_table(User::as_table());
+    /// assert_eq!(select.build(), r#"SELECT "User"."id","User"."name" FROM "User""#);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if an excluded name is not a column of the table.
+    pub fn push_columns_except<T: SqlTable>(&mut self, exclude: &[&str]) {
+        #[cfg(debug_assertions)]
+        if let Some(name) = exclude.iter().find(|name| !T::has_column(name)) {
+            panic!("no column {name:?} in table {}", T::TABLE_NAME);
+        }
+        self.push_columns(
+            T::COLUMNS
+                .iter()
+                .filter(|col| !exclude.contains(&col.name()))
+                .copied(),
+        );
+    }
+
+    /// Returns the names of the tables referenced by the selection: the
+    /// table set first, followed by the joined ones
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let select = Select::new()
+    ///     .with_table(User::as_table())
+    ///     .with_join(UserData::as_table(), [User::DataId.eq(UserData::Id)])
+    ///     .with_left_join(Order::as_table(), [Order::UserId.eq(User::Id)]);
+    /// assert_eq!(select.referenced_tables(), ["User", "UserData", "Order"]);
+    /// ```
+    pub fn referenced_tables(&self) -> &[&'static str] {
+        &self.tables
+    }
+
+    /// Removes duplicate columns from the selection, keeping the first
+    /// occurrence of each
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut select = Select::new();
+    /// select.push_columns([User::Id, User::Name, User::Id]);
+    /// select.dedup_columns();
+    /// select.set_table(User::as_table());
+    /// assert_eq!(select.build(), r#"SELECT "User"."id","User"."name" FROM "User""#);
+    /// ```
+    pub fn dedup_columns(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.columns.retain(|col| seen.insert(col.sql.clone()));
+    }
+
     /// Sets the table from which the selection will be made
     ///
     /// # Panic
@@ -190,6 +458,27 @@ impl Select {
             panic!("table already exists, use join instead");
         }
         self.table = T::TABLE_NAME.into();
+        self.tables.push(trim_quotes(T::TABLE_NAME));
+    }
+
+    /// Sets the table from which the selection will be made, excluding the
+    /// tables inheriting it: `FROM ONLY {table}` (Postgres)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut select = Select::new();
+    /// select.push_column(Measurement::Value);
+    /// select.set_table_only(Measurement::as_table());
+    /// assert_eq!(select.build(), r#"SELECT "Measurement"."value" FROM ONLY "Measurement""#);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has already been set earlier. Use joins in this case.
+    pub fn set_table_only<T: SqlTable>(&mut self, table: T) {
+        self.set_table(table);
+        self.table.insert_str(0, "ONLY ");
     }
 
     /// Joins the table for the selection
@@ -222,7 +511,11 @@ impl Select {
         // use write to eliminate unnecessary allocations
         use std::fmt::Write;
         let on_cond = format_cond(on);
-        write!(self.table, " INNER JOIN {} ON {on_cond}", T::TABLE_NAME).unwrap();
+        if on_cond.is_empty() && self.join_without_on.is_none() {
+            self.join_without_on = Some(trim_quotes(T::TABLE_NAME));
+        }
+        write!(self.joins, " INNER JOIN {} ON {on_cond}", T::TABLE_NAME).unwrap();
+        self.tables.push(trim_quotes(T::TABLE_NAME));
     }
 
     /// Joins the table for the selection
@@ -242,7 +535,52 @@ impl Select {
         // use write to eliminate unnecessary allocations
         use std::fmt::Write;
         let on_cond = format_cond(on);
-        write!(self.table, " LEFT JOIN {} ON {on_cond}", T::TABLE_NAME).unwrap();
+        if on_cond.is_empty() && self.join_without_on.is_none() {
+            self.join_without_on = Some(trim_quotes(T::TABLE_NAME));
+        }
+        write!(self.joins, " LEFT JOIN {} ON {on_cond}", T::TABLE_NAME).unwrap();
+        self.tables.push(trim_quotes(T::TABLE_NAME));
+    }
+
+    /// Joins every row of the table to every row of the selection:
+    /// `CROSS JOIN {table}`
+    ///
+    /// # Panic
+    ///
+    /// Panics if the table has not been set. First set the table as a starting point.
+    pub fn cross_join<T: SqlTable>(&mut self, _table: T) {
+        #[cfg(debug_assertions)]
+        if self.table.is_empty() {
+            panic!("join to nothing, use with_table or set_table first");
+        }
+        // use write to eliminate unnecessary allocations
+        use std::fmt::Write;
+        write!(self.joins, " CROSS JOIN {}", T::TABLE_NAME).unwrap();
+        self.tables.push(trim_quotes(T::TABLE_NAME));
+    }
+
+    /// Selects only a sample of the table rows, given as a percentage:
+    /// `TABLESAMPLE SYSTEM ({percent})` (Postgres)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut select = Select::new();
+    /// select.push_column(User::Name);
+    /// select.set_table(User::as_table());
+    /// select.set_sample(10.0);
+    /// assert_eq!(select.build(), r#"SELECT "User"."name" FROM "User" TABLESAMPLE SYSTEM (10)"#);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if the percentage is not in range `0..=100`.
+    pub fn set_sample(&mut self, percent: f64) {
+        #[cfg(debug_assertions)]
+        if !(0.0..=100.0).contains(&percent) {
+            panic!("sample percentage must be in range 0..=100");
+        }
+        self.sample = Some(percent);
     }
 
     /// Adds a selection condition
@@ -251,19 +589,37 @@ impl Select {
     }
 
     /// Adds a column to sort the selection
-    pub fn push_order<O: Into<String>>(&mut self, order: O) {
-        if !self.order_by.is_empty() {
-            self.order_by.push(',');
-        }
-        self.order_by += &order.into();
+    pub fn push_order<O: Into<OrderBy>>(&mut self, order: O) {
+        self.order_by.push(order.into());
+    }
+
+    /// Sets the placement of `NULL` for the `asc()`/`desc()` orderings,
+    /// which is rendered according to the dialect the query is built for
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let mut select = Select::new();
+    /// select.push_column(User::Name);
+    /// select.set_table(User::as_table());
+    /// select.push_order(User::Age.asc());
+    /// select.set_nulls_order(NullsOrder::Largest);
+    /// assert_eq!(
+    ///     select.clone().build_for(Dialect::Sqlite),
+    ///     r#"SELECT "User"."name" FROM "User" ORDER BY "User"."age" ASC NULLS LAST"#
+    /// );
+    /// assert_eq!(
+    ///     select.build_for(Dialect::MySql),
+    ///     "SELECT `User`.`name` FROM `User` ORDER BY `User`.`age` IS NULL ASC, `User`.`age` ASC"
+    /// );
+    /// ```
+    pub fn set_nulls_order(&mut self, nulls: NullsOrder) {
+        self.nulls_order = nulls;
     }
 
     /// Adds a column to group the selection.
     pub fn push_group<G: Into<String>>(&mut self, group: G) {
-        if !self.group_by.is_empty() {
-            self.group_by.push(',');
-        }
-        self.group_by += &group.into();
+        self.group_by.push(group.into());
     }
 
     /// Adds a condition for grouping the selection
@@ -281,33 +637,390 @@ impl Select {
         self.offset = Some(offset);
     }
 
-    /// Performs query building by consuming itself
+    /// Merges the `WHERE` and `HAVING` conditions, `GROUP BY` and
+    /// `ORDER BY` of another select into this one. The table and the columns
+    /// of `self` are kept as is
+    ///
+    /// `LIMIT` and `OFFSET` of `self` take precedence, the values of `other`
+    /// are only used if they are not set in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let filters = Select::new()
+    ///     .with_where_cond(User::Active.eq(true))
+    ///     .with_order(User::Name.asc());
+    /// let mut select = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_where_cond(User::Age.gt(30));
+    /// select.append(&filters);
+    /// assert_eq!(
+    ///     select.build(),
+    ///     r#"SELECT "User"."name" FROM "User" WHERE ("User"."age" > 30) AND ("User"."active" = 1) ORDER BY "User"."name" ASC"#
+    /// );
+    /// ```
+    pub fn append(&mut self, other: &Select) {
+        self.cond.extend(other.cond.iter().cloned());
+        self.group_by.extend(other.group_by.iter().cloned());
+        self.having.extend(other.having.iter().cloned());
+        self.order_by.extend(other.order_by.iter().cloned());
+        self.limit = self.limit.or(other.limit);
+        self.offset = self.offset.or(other.offset);
+    }
+
+    /// Performs query building by consuming itself for the default
+    /// [`Dialect`] (SQLite), see [`build_for`](Self::build_for)
+    ///
+    /// Note that booleans are rendered as `1`/`0`, and strings and dates as
+    /// single-quoted literals like `'2025-01-01'`.
     pub fn build(self) -> String {
-        let mut sql = format!("SELECT {} FROM {}", self.columns, self.table);
+        self.build_for(Dialect::default())
+    }
+
+    /// Performs query building by consuming itself for the specified dialect
+    ///
+    /// This applies the parts of the query rendered at build time: identifier
+    /// quoting, booleans, explicit placement of `NULL` (see
+    /// [`with_nulls_order`](Self::with_nulls_order)), the placeholder style
+    /// (see [`build_prepared_for`](Self::build_prepared_for)) and the
+    /// `LIMIT`/`OFFSET` syntax, which differs when only an offset is set.
+    /// Functions and operators are rendered when they are pushed, so the
+    /// helpers that differ between engines take the dialect explicitly.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let select = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_limit_offset(20);
+    /// assert_eq!(
+    ///     select.clone().build_for(Dialect::Sqlite),
+    ///     r#"SELECT "User"."name" FROM "User" LIMIT -1 OFFSET 20"#
+    /// );
+    /// assert_eq!(
+    ///     select.clone().build_for(Dialect::Postgres),
+    ///     r#"SELECT "User"."name" FROM "User" OFFSET 20"#
+    /// );
+    /// assert_eq!(
+    ///     select.build_for(Dialect::MySql),
+    ///     "SELECT `User`.`name` FROM `User` LIMIT 18446744073709551615 OFFSET 20"
+    /// );
+    /// ```
+    pub fn build_for(self, dialect: Dialect) -> String {
+        self.render(None, dialect)
+    }
+
+    /// Performs query building by consuming itself, but first checks the
+    /// query for common mistakes:
+    /// - if there is an aggregate among the selected columns, every plain
+    ///   column (pushed as [`SqlColumn`]) must also be in `GROUP BY`
+    /// - every join, except `CROSS JOIN`, must have an `ON` condition,
+    ///   otherwise it's likely an accidental cartesian product
+    /// - every plain column must belong to the selected or a joined table
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let result = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_column(User::Id.count())
+    ///     .with_table(User::as_table())
+    ///     .try_build();
+    /// assert!(matches!(result, Err(SelectError::MissingGroupBy { .. })));
+    /// ```
+    pub fn try_build(self) -> Result<String, SelectError> {
+        if let Some(table) = self.join_without_on {
+            return Err(SelectError::PossibleCartesianProduct { table });
+        }
+        let unknown = self
+            .columns
+            .iter()
+            .filter_map(|col| col.column)
+            .find(|col| !self.tables.contains(&col.table_name()));
+        if let Some(column) = unknown {
+            return Err(SelectError::UnknownColumn {
+                column: column.to_string(),
+                table: column.table_name(),
+            });
+        }
+        if self.columns.iter().any(|col| col.aggregate) {
+            let missing = self
+                .columns
+                .iter()
+                .filter_map(|col| col.column)
+                .find(|col| !self.group_by.contains(&col.to_string()));
+            if let Some(column) = missing {
+                return Err(SelectError::MissingGroupBy {
+                    column: column.to_string(),
+                });
+            }
+        }
+        Ok(self.build())
+    }
+
+    /// Performs query building by consuming itself and wraps the query in
+    /// parentheses: `(SELECT ...)`, so it can be embedded as a subquery
+    ///
+    /// The dialect must be the one the outer query is built for. Quoting and
+    /// booleans are left in the common form and adapted along with the outer
+    /// query.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sub = Select::new()
+    ///     .with_column(UserData::Id)
+    ///     .with_table(UserData::as_table())
+    ///     .parenthesized(Dialect::MySql);
+    /// assert_eq!(sub, r#"(SELECT "UserData"."id" FROM "UserData")"#);
+    /// let sql = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_where_cond(format!("{} IN {sub}", User::DataId))
+    ///     .build_for(Dialect::MySql);
+    /// ```
+    pub fn parenthesized(self, dialect: Dialect) -> String {
+        format!("({})", self.render_common(None, dialect))
+    }
+
+    /// Performs query building by consuming itself, emitting `LIMIT` and
+    /// `OFFSET` as placeholders (see [`Dialect::placeholder`]). Their values
+    /// are returned separately and must be bound in the same order
+    ///
+    /// Conditions are still inlined, because they are passed as SQL strings.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let (sql, args) = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_limit(10)
+    ///     .with_limit_offset(20)
+    ///     .build_prepared();
+    /// assert_eq!(sql, r#"SELECT "User"."name" FROM "User" LIMIT ? OFFSET ?"#);
+    /// assert_eq!(args, [10, 20]);
+    ///
+    /// let mut query = sqlx::query(&sql);
+    /// for arg in args {
+    ///     query = query.bind(arg);
+    /// }
+    /// ```
+    pub fn build_prepared(self) -> (String, Vec<i64>) {
+        self.build_prepared_for(Dialect::default())
+    }
+
+    /// Same as [`build_prepared`](Self::build_prepared), but for the
+    /// specified dialect (see [`build_for`](Self::build_for))
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let select = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_limit(10);
+    /// let (sql, _) = select.clone().build_prepared_for(Dialect::Postgres);
+    /// assert_eq!(sql, r#"SELECT "User"."name" FROM "User" LIMIT $1"#);
+    /// let (sql, _) = select.build_prepared_for(Dialect::MySql);
+    /// assert_eq!(sql, "SELECT `User`.`name` FROM `User` LIMIT ?");
+    /// ```
+    pub fn build_prepared_for(self, dialect: Dialect) -> (String, Vec<i64>) {
+        let mut args = vec![];
+        let sql = self.render(Some(&mut args), dialect);
+        (sql, args)
+    }
+
+    /// Returns the query with the values that
+    /// [`build_prepared`](Self::build_prepared) would bind inlined back into
+    /// it, without consuming the builder
+    ///
+    /// It is intended for logging only; to execute the query, use
+    /// `build_prepared` and bind the arguments.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let select = Select::new()
+    ///     .with_column(User::Name)
+    ///     .with_table(User::as_table())
+    ///     .with_limit(10);
+    /// log::debug!("{}", select.to_sql_debug());
+    /// let (sql, args) = select.build_prepared();
+    /// ```
+    pub fn to_sql_debug(&self) -> String {
+        self.render(None, Dialect::default())
+    }
+
+    /// Converts the select into an immutable one that is cheap to clone and
+    /// share between threads
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let base = Select::new()
+    ///     .with_columns([User::Id, User::Name])
+    ///     .with_table(User::as_table())
+    ///     .freeze();
+    /// let handle = std::thread::spawn({
+    ///     let base = base.clone();
+    ///     move || base.build()
+    /// });
+    /// assert_eq!(handle.join().unwrap(), base.build());
+    /// ```
+    pub fn freeze(self) -> FrozenSelect {
+        FrozenSelect(Arc::new(self))
+    }
+
+    fn render(&self, args: Option<&mut Vec<i64>>, dialect: Dialect) -> String {
+        crate::dialect::localize(&self.render_common(args, dialect), dialect)
+    }
+
+    /// Renders the query in the common form, which is adapted to the dialect
+    /// only by [`render`](Self::render), so a subquery is adapted once along
+    /// with the outer query
+    fn render_common(&self, mut args: Option<&mut Vec<i64>>, dialect: Dialect) -> String {
+        let columns: Vec<&str> = self.columns.iter().map(|col| col.sql.as_str()).collect();
+        let mut sql = format!("SELECT {} FROM {}", columns.join(","), self.table);
+        if let Some(percent) = self.sample {
+            // use write to eliminate unnecessary allocations
+            use std::fmt::Write;
+            write!(sql, " TABLESAMPLE SYSTEM ({percent})").unwrap();
+        }
+        sql += &self.joins;
         if !self.cond.is_empty() {
             sql += " WHERE ";
-            sql += &format_cond(self.cond);
+            sql += &format_cond(self.cond.iter().cloned());
         }
         if !self.group_by.is_empty() {
             sql += " GROUP BY ";
-            sql += &self.group_by;
+            sql += &self.group_by.join(",");
         }
         if !self.having.is_empty() {
             sql += " HAVING ";
-            sql += &format_cond(self.having);
+            sql += &format_cond(self.having.iter().cloned());
         }
         if !self.order_by.is_empty() {
             sql += " ORDER BY ";
-            sql += &self.order_by;
+            let order_by: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|order| order.render(self.nulls_order, dialect))
+                .collect();
+            sql += &order_by.join(",");
         }
-        if let Some(limit) = self.limit {
-            // use write to eliminate unnecessary allocations
-            use std::fmt::Write;
-            write!(sql, " LIMIT {limit}").unwrap();
-            if let Some(offset) = self.offset {
-                write!(sql, " OFFSET {offset}").unwrap();
+        // inlines the value, or binds it if the args are collected
+        let mut value = |value: u32| match args.as_deref_mut() {
+            Some(args) => {
+                args.push(value.into());
+                dialect.placeholder(args.len())
+            }
+            None => value.to_string(),
+        };
+        // use write to eliminate unnecessary allocations
+        use std::fmt::Write;
+        match (self.limit, self.offset) {
+            (Some(limit), offset) => {
+                write!(sql, " LIMIT {}", value(limit)).unwrap();
+                if let Some(offset) = offset {
+                    write!(sql, " OFFSET {}", value(offset)).unwrap();
+                }
             }
+            // an offset without a limit is only allowed by Postgres, the
+            // others require a limit, so the largest possible one is used
+            (None, Some(offset)) => {
+                match dialect {
+                    Dialect::Sqlite => sql += " LIMIT -1",
+                    Dialect::Postgres => {}
+                    Dialect::MySql => sql += " LIMIT 18446744073709551615",
+                }
+                write!(sql, " OFFSET {}", value(offset)).unwrap();
+            }
+            (None, None) => {}
         }
         sql
     }
 }
+
+#[cfg(feature = "sqlx")]
+impl Select {
+    /// Performs query building by consuming itself and fetches all rows,
+    /// mapping them into `T`
+    ///
+    /// The query is built for the dialect of the database driver.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let users: Vec<User> = User::query()
+    ///     .with_where_cond(User::DataId.is_not_null())
+    ///     .fetch_all(&pool)
+    ///     .await?;
+    /// ```
+    pub async fn fetch_all<'e, T, E, DB>(self, executor: E) -> Result<Vec<T>, sqlx::Error>
+    where
+        T: SqlTable + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+        E: 'e + sqlx::Executor<'e, Database = DB>,
+        DB: sqlx::Database,
+        for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    {
+        let sql = self.build_for(Dialect::of::<DB>());
+        sqlx::query_as::<DB, T>(&sql).fetch_all(executor).await
+    }
+
+    /// Performs query building by consuming itself and fetches at most one
+    /// row, mapping it into `T`
+    pub async fn fetch_optional<'e, T, E, DB>(self, executor: E) -> Result<Option<T>, sqlx::Error>
+    where
+        T: SqlTable + for<'r> sqlx::FromRow<'r, DB::Row> + Send + Unpin,
+        E: 'e + sqlx::Executor<'e, Database = DB>,
+        DB: sqlx::Database,
+        for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    {
+        let sql = self.build_for(Dialect::of::<DB>());
+        sqlx::query_as::<DB, T>(&sql).fetch_optional(executor).await
+    }
+}
+
+/// An immutable [`Select`] created by [`Select::freeze`]
+///
+/// Cloning it only increments a reference counter, so a base query can be
+/// built once and shared between threads.
+#[derive(Clone)]
+pub struct FrozenSelect(Arc<Select>);
+
+impl FrozenSelect {
+    /// Performs query building, see [`Select::build`]
+    pub fn build(&self) -> String {
+        self.build_for(Dialect::default())
+    }
+
+    /// Performs query building, see [`Select::build_for`]
+    pub fn build_for(&self, dialect: Dialect) -> String {
+        self.0.render(None, dialect)
+    }
+
+    /// Performs query building, see [`Select::build_prepared`]
+    pub fn build_prepared(&self) -> (String, Vec<i64>) {
+        self.build_prepared_for(Dialect::default())
+    }
+
+    /// Performs query building, see [`Select::build_prepared_for`]
+    pub fn build_prepared_for(&self, dialect: Dialect) -> (String, Vec<i64>) {
+        let mut args = vec![];
+        let sql = self.0.render(Some(&mut args), dialect);
+        (sql, args)
+    }
+
+    /// Returns a mutable copy of the select to continue building on it
+    pub fn thaw(&self) -> Select {
+        Select::clone(&self.0)
+    }
+
+    /// Returns `true` if both selects share the same data
+    pub fn ptr_eq(&self, other: &FrozenSelect) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::SqlValue;
+use crate::sql_value::quote_literal;
+use crate::{Dialect, Expr, OrderBy, Projection, SqlInterval, SqlValue};
 
 /// Information about the column entity for working with SQL
 #[derive(Clone, Copy)]
@@ -6,6 +7,7 @@ pub struct SqlColumn {
     name: &'static str,
     table_name: &'static str,
     is_primary: bool,
+    comment: Option<&'static str>,
 }
 
 impl std::fmt::Display for SqlColumn {
@@ -27,6 +29,51 @@ impl SqlValue for SqlColumn {
     }
 }
 
+/// Produces `(A + B)`
+impl<V: SqlValue> std::ops::Add<V> for SqlColumn {
+    type Output = Expr;
+
+    fn add(self, value: V) -> Expr {
+        Expr::new(format!("({self} + {})", value.to_sql()))
+    }
+}
+
+/// Produces `(A - B)`
+impl<V: SqlValue> std::ops::Sub<V> for SqlColumn {
+    type Output = Expr;
+
+    fn sub(self, value: V) -> Expr {
+        Expr::new(format!("({self} - {})", value.to_sql()))
+    }
+}
+
+/// Produces `(A * B)`
+impl<V: SqlValue> std::ops::Mul<V> for SqlColumn {
+    type Output = Expr;
+
+    fn mul(self, value: V) -> Expr {
+        Expr::new(format!("({self} * {})", value.to_sql()))
+    }
+}
+
+/// Produces `(A / B)`
+impl<V: SqlValue> std::ops::Div<V> for SqlColumn {
+    type Output = Expr;
+
+    fn div(self, value: V) -> Expr {
+        Expr::new(format!("({self} / {})", value.to_sql()))
+    }
+}
+
+/// Produces `(A % B)`
+impl<V: SqlValue> std::ops::Rem<V> for SqlColumn {
+    type Output = Expr;
+
+    fn rem(self, value: V) -> Expr {
+        Expr::new(format!("({self} % {})", value.to_sql()))
+    }
+}
+
 impl SqlColumn {
     /// Creates a new sql column
     ///
@@ -50,9 +97,22 @@ impl SqlColumn {
             name,
             table_name,
             is_primary,
+            comment: None,
         }
     }
 
+    /// Sets the description of the column (e.g. from `COMMENT` in the schema)
+    ///
+    /// ```no_run
+    /// let email_col = unsafe { SqlColumn::new("\"email\"", "\"User\"", false) }
+    ///     .with_comment("Primary contact address");
+    /// assert_eq!(email_col.comment(), Some("Primary contact address"));
+    /// ```
+    pub const fn with_comment(mut self, comment: &'static str) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
     /// Returns name of the column
     pub const fn name(&self) -> &'static str {
         trim_quotes(self.name)
@@ -63,74 +123,116 @@ impl SqlColumn {
         trim_quotes(self.table_name)
     }
 
+    /// Returns the description of the column, if there is one
+    pub const fn comment(&self) -> Option<&'static str> {
+        self.comment
+    }
+
     /// Returns `true` if a primary key is defined for the column
     pub fn is_primary(&self) -> bool {
         self.is_primary
     }
 
     /// Produces an alias: `{field} AS {alias}`
-    pub fn alias(self, alias: &str) -> String {
-        format!("{self} AS {alias:?}")
+    pub fn alias(self, alias: &str) -> Projection {
+        Projection::from(self).with_alias(alias)
     }
 
     /// Produces `COUNT({field})`
-    pub fn count(self) -> String {
-        format!("COUNT({self})")
+    ///
+    /// Note that rows where the column is `NULL` are not counted, use
+    /// [`count_star`](crate::count_star) to count all rows.
+    pub fn count(self) -> Expr {
+        Expr::aggregate(format!("COUNT({self})"))
     }
 
     /// Produces `COUNT({field}) AS {alias}`
-    pub fn count_as(self, alias: &str) -> String {
-        format!("COUNT({self}) AS {alias:?}")
+    pub fn count_as(self, alias: &str) -> Projection {
+        self.count().alias(alias)
+    }
+
+    /// Same as [`count`](Self::count), but makes it explicit that only
+    /// non-`NULL` values are counted: `COUNT({field})`
+    pub fn count_nonnull(self) -> Expr {
+        self.count()
     }
 
     /// Produces `SUM({field})`
-    pub fn sum(self) -> String {
-        format!("SUM({self})")
+    pub fn sum(self) -> Expr {
+        Expr::aggregate(format!("SUM({self})"))
     }
 
     /// Produces `SUM({field}) AS {alias}`
-    pub fn sum_as(self, alias: &str) -> String {
-        format!("SUM({self}) AS {alias:?}")
+    pub fn sum_as(self, alias: &str) -> Projection {
+        self.sum().alias(alias)
     }
 
     /// Produces `AVG({field})`
-    pub fn avg(self) -> String {
-        format!("AVG({self})")
+    pub fn avg(self) -> Expr {
+        Expr::aggregate(format!("AVG({self})"))
     }
 
     /// Produces `AVG({field}) AS {alias}`
-    pub fn avg_as(self, alias: &str) -> String {
-        format!("AVG({self}) AS {alias}")
+    pub fn avg_as(self, alias: &str) -> Projection {
+        Expr::aggregate(format!("AVG({self}) AS {alias}")).into()
     }
 
     /// Produces `MIN({field})`
-    pub fn min(self) -> String {
-        format!("MIN({self})")
+    pub fn min(self) -> Expr {
+        Expr::aggregate(format!("MIN({self})"))
     }
 
     /// Produces `MIN({field}) AS {alias}`
-    pub fn min_as(self, alias: &str) -> String {
-        format!("MIN({self}) AS {alias}")
+    pub fn min_as(self, alias: &str) -> Projection {
+        Expr::aggregate(format!("MIN({self}) AS {alias}")).into()
     }
 
     /// Produces `MAX({field})`
-    pub fn max(self) -> String {
-        format!("MAX({self})")
+    pub fn max(self) -> Expr {
+        Expr::aggregate(format!("MAX({self})"))
     }
 
     /// Produces `MAX({field}) AS {alias}`
-    pub fn max_as(self, alias: &str) -> String {
-        format!("MAX({self}) AS {alias}")
+    pub fn max_as(self, alias: &str) -> Projection {
+        Expr::aggregate(format!("MAX({self}) AS {alias}")).into()
+    }
+
+    /// Produces `MOD(A, B)`, the function form of `A % B`
+    pub fn mod_fn<V: SqlValue>(self, value: V) -> Expr {
+        Expr::new(format!("MOD({self}, {})", value.to_sql()))
+    }
+
+    /// Produces the modulo for the specified [`Dialect`]: `(A % B)` for SQLite
+    /// (where `MOD` requires the math functions to be compiled in) and
+    /// `MOD(A, B)` for the others
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = User::Id.modulo(2, Dialect::Sqlite).eq(0);
+    /// assert_eq!(cond, r#"("User"."id" % 2) = 0"#);
+    /// ```
+    pub fn modulo<V: SqlValue>(self, value: V, dialect: Dialect) -> Expr {
+        match dialect {
+            Dialect::Sqlite => self % value,
+            Dialect::Postgres | Dialect::MySql => self.mod_fn(value),
+        }
     }
 
     /// Produces `{field} ASC` (for ordering)
-    pub fn asc(self) -> String {
-        format!("{self} ASC")
+    ///
+    /// Explicit placement of `NULL` can be added when building, see
+    /// [`Select::with_nulls_order`](crate::Select::with_nulls_order).
+    pub fn asc(self) -> OrderBy {
+        OrderBy::new(self.to_string(), false)
     }
 
     /// Produces `{field} DESC` (for ordering)
-    pub fn desc(self) -> String {
-        format!("{self} DESC")
+    ///
+    /// Explicit placement of `NULL` can be added when building, see
+    /// [`Select::with_nulls_order`](crate::Select::with_nulls_order).
+    pub fn desc(self) -> OrderBy {
+        OrderBy::new(self.to_string(), true)
     }
 
     /// Produces `{field} IS NULL`
@@ -148,6 +250,17 @@ impl SqlColumn {
         format!("{self} = {}", value.to_sql())
     }
 
+    /// Produces a `NULL`-safe equality for the specified [`Dialect`]:
+    /// `A <=> B` for MySQL and `A IS NOT DISTINCT FROM B` for the others
+    pub fn eq_null_safe<V: SqlValue>(self, value: V, dialect: Dialect) -> String {
+        match dialect {
+            Dialect::MySql => format!("{self} <=> {}", value.to_sql()),
+            Dialect::Sqlite | Dialect::Postgres => {
+                format!("{self} IS NOT DISTINCT FROM {}", value.to_sql())
+            }
+        }
+    }
+
     /// Produces `A != B`
     pub fn ne<V: SqlValue>(self, value: V) -> String {
         format!("{self} != {}", value.to_sql())
@@ -183,6 +296,56 @@ impl SqlColumn {
         format!("{self} NOT LIKE {}", value.to_sql())
     }
 
+    /// Produces `LOWER(A) = LOWER('value')` for case-insensitive lookups
+    /// (matches a functional index on `LOWER(A)`)
+    pub fn lower_eq(self, value: &str) -> String {
+        format!("LOWER({self}) = LOWER({})", quote_literal(value))
+    }
+
+    /// Produces `LOWER(A) LIKE LOWER('pattern')`
+    pub fn lower_like(self, pattern: &str) -> String {
+        format!("LOWER({self}) LIKE LOWER({})", quote_literal(pattern))
+    }
+
+    /// Produces `A LIKE 'pattern' ESCAPE 'x'`, where wildcards preceded by the
+    /// `escape` character are matched literally
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = User::Name.like_pattern("100!%%", '!');
+    /// assert_eq!(cond, r#""User"."name" LIKE '100!%%' ESCAPE '!'"#)
+    /// ```
+    pub fn like_pattern(self, pattern: &str, escape: char) -> String {
+        format!(
+            "{self} LIKE {} ESCAPE {}",
+            quote_literal(pattern),
+            quote_literal(escape.encode_utf8(&mut [0; 4]))
+        )
+    }
+
+    /// Produces `(A = B) OR (A = C) ...`, an alternative to
+    /// [`in_list`](Self::in_list) spelled out with `OR`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = Order::Status.eq_any_of(["new", "paid"]);
+    /// assert_eq!(cond, r#"("Order"."status" = 'new') OR ("Order"."status" = 'paid')"#)
+    /// ```
+    pub fn eq_any_of<I>(self, values: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: SqlValue,
+    {
+        let tmp: Vec<String> = values.into_iter().map(|val| self.eq(val)).collect();
+        if tmp.is_empty() {
+            String::from("false")
+        } else {
+            crate::join_or(tmp)
+        }
+    }
+
     /// Produces `A IN (...)`
     pub fn in_list<I>(self, values: I) -> String
     where
@@ -211,6 +374,43 @@ impl SqlColumn {
         }
     }
 
+    /// Produces `A IN (SELECT v FROM (VALUES (...),(...)) AS t(v))`, which
+    /// some engines handle better than a long `IN (...)` list
+    ///
+    /// The derived table is rendered for the specified [`Dialect`]: SQLite
+    /// selects the implicit `column1`, MySQL wraps each value in `ROW(...)`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = User::Id.in_values_table([1, 2], Dialect::Postgres);
+    /// assert_eq!(cond, r#""User"."id" IN (SELECT v FROM (VALUES (1),(2)) AS t(v))"#)
+    /// ```
+    pub fn in_values_table<I>(self, values: I, dialect: Dialect) -> String
+    where
+        I: IntoIterator,
+        I::Item: SqlValue,
+    {
+        let row = match dialect {
+            Dialect::MySql => "ROW",
+            Dialect::Sqlite | Dialect::Postgres => "",
+        };
+        let tmp: Vec<String> = values
+            .into_iter()
+            .map(|val| format!("{row}({})", val.to_sql()))
+            .collect();
+        if tmp.is_empty() {
+            return String::from("false");
+        }
+        let rows = tmp.join(",");
+        match dialect {
+            Dialect::Sqlite => format!("{self} IN (SELECT column1 FROM (VALUES {rows}))"),
+            Dialect::Postgres | Dialect::MySql => {
+                format!("{self} IN (SELECT v FROM (VALUES {rows}) AS t(v))")
+            }
+        }
+    }
+
     /// Produces `A BETWEEN (B) AND (C)`
     pub fn between<L, R>(self, left: L, right: R) -> String
     where
@@ -223,9 +423,71 @@ impl SqlColumn {
             right.to_sql()
         )
     }
+
+    /// Produces `(A >= B) AND (A < C)`: a half-open range, unlike
+    /// [`between`](Self::between) it doesn't include the upper bound
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = Order::CreateDate.in_range(date!(2025 - 01 - 01), date!(2025 - 02 - 01));
+    /// assert_eq!(cond, r#"("Order"."create_date" >= '2025-01-01') AND ("Order"."create_date" < '2025-02-01')"#)
+    /// ```
+    pub fn in_range<L, R>(self, low: L, high: R) -> String
+    where
+        L: SqlValue,
+        R: SqlValue,
+    {
+        crate::join_and([self.ge(low), self.lt(high)])
+    }
+
+    /// Produces a condition that the column is within the last interval
+    /// of time for the specified [`Dialect`]:
+    /// - SQLite: `A >= datetime('now', '-7 days')`
+    /// - Postgres and MySQL: `A >= CURRENT_TIMESTAMP - INTERVAL '7' DAY`
+    pub fn within_last(self, interval: SqlInterval, dialect: Dialect) -> String {
+        match dialect {
+            Dialect::Sqlite => format!("{self} >= datetime('now', '-{}')", interval.modifier()),
+            Dialect::Postgres | Dialect::MySql => {
+                format!("{self} >= CURRENT_TIMESTAMP - {}", interval.to_sql())
+            }
+        }
+    }
+
+    /// Produces `A @> 'json'::jsonb`: the JSON document contains the
+    /// specified one (Postgres)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let cond = User::Tags.json_contains(r#"["admin"]"#);
+    /// assert_eq!(cond, r#""User"."tags" @> '["admin"]'::jsonb"#)
+    /// ```
+    pub fn json_contains(self, value: &str) -> String {
+        format!("{self} @> {}::jsonb", quote_literal(value))
+    }
+
+    /// Produces `A ? 'key'`: the JSON object has the key at the top level
+    /// (Postgres)
+    pub fn json_has_key(self, key: &str) -> String {
+        format!("{self} ? {}", quote_literal(key))
+    }
+
+    /// Produces a full-text search condition for the specified [`Dialect`]:
+    /// - SQLite (FTS tables): `A MATCH 'query'`
+    /// - Postgres: `to_tsvector(A) @@ plainto_tsquery('query')`
+    /// - MySQL: `MATCH (A) AGAINST ('query')`
+    pub fn matches_fts(self, query: &str, dialect: Dialect) -> String {
+        let query = quote_literal(query);
+        match dialect {
+            Dialect::Sqlite => format!("{self} MATCH {query}"),
+            Dialect::Postgres => format!("to_tsvector({self}) @@ plainto_tsquery({query})"),
+            Dialect::MySql => format!("MATCH ({self}) AGAINST ({query})"),
+        }
+    }
 }
 
-const fn trim_quotes(s: &'static str) -> &'static str {
+pub(crate) const fn trim_quotes(s: &'static str) -> &'static str {
     // all these complexities are needed to make the function `const`
 
     // convert to a byte slice, because we don't know how
@@ -256,7 +518,7 @@ const fn trim_quotes(s: &'static str) -> &'static str {
                     // memory leaks are excluded. And we know for sure that
                     // the slice length allows us to reduce its size by 2
                     // bytes.
-                    let new_slice = std::slice::from_raw_parts(bytes.as_ptr().add(1), len - 1);
+                    let new_slice = std::slice::from_raw_parts(bytes.as_ptr().add(1), len - 2);
                     std::str::from_utf8_unchecked(new_slice)
                 }
             }
@@ -0,0 +1,96 @@
+use crate::SqlValue;
+
+/// Unit of an [`SqlInterval`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl IntervalUnit {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+}
+
+/// A time interval, rendered as the standard `INTERVAL '7' DAY`
+///
+/// The amount is unsigned, the direction is given by the function that uses
+/// the interval (like [`SqlColumn::within_last`](crate::SqlColumn::within_last)).
+/// As a value it's only supported by Postgres and MySQL, SQLite has no
+/// interval type and uses date modifiers instead.
+///
+/// # Example
+///
+/// ```no_run
+/// let cond = Order::CreateDate.within_last(SqlInterval::days(7), Dialect::Postgres);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SqlInterval {
+    amount: u32,
+    unit: IntervalUnit,
+}
+
+impl SqlValue for SqlInterval {
+    /// Produces `INTERVAL '7' DAY` (Postgres and MySQL only)
+    fn to_sql(&self) -> String {
+        format!(
+            "INTERVAL '{}' {}",
+            self.amount,
+            self.unit.name().to_uppercase()
+        )
+    }
+}
+
+impl SqlInterval {
+    /// Creates an interval of the specified number of units
+    pub const fn new(amount: u32, unit: IntervalUnit) -> Self {
+        Self { amount, unit }
+    }
+
+    /// Creates an interval of the specified number of seconds
+    pub const fn seconds(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Second)
+    }
+
+    /// Creates an interval of the specified number of minutes
+    pub const fn minutes(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Minute)
+    }
+
+    /// Creates an interval of the specified number of hours
+    pub const fn hours(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Hour)
+    }
+
+    /// Creates an interval of the specified number of days
+    pub const fn days(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Day)
+    }
+
+    /// Creates an interval of the specified number of months
+    pub const fn months(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Month)
+    }
+
+    /// Creates an interval of the specified number of years
+    pub const fn years(amount: u32) -> Self {
+        Self::new(amount, IntervalUnit::Year)
+    }
+
+    /// Returns the interval as `7 days` (also a valid SQLite date modifier)
+    pub(crate) fn modifier(&self) -> String {
+        format!("{} {}s", self.amount, self.unit.name())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::SqlColumn;
+use crate::sql_column::trim_quotes;
+use crate::{Dialect, Select, SqlColumn};
 
 /// Represents any type that can be considered as an SQL table
 pub trait SqlTable: Default {
@@ -39,4 +40,139 @@ pub trait SqlTable: Default {
     fn column_name_at(index: usize) -> Option<&'static str> {
         Self::COLUMNS.get(index).map(|col| col.name())
     }
+
+    /// Returns a [`Select`] of all columns from the table, ready for adding
+    /// conditions
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sql = User::query().with_where_cond(User::Name.eq("Bob")).build();
+    /// assert_eq!(
+    ///     sql,
+    ///     r#"SELECT "User"."id","User"."name","User"."data_id" FROM "User" WHERE ("User"."name" = 'Bob')"#
+    /// );
+    /// ```
+    fn query() -> Select {
+        Select::new()
+            .with_columns(Self::COLUMNS.iter().copied())
+            .with_table(Self::as_table())
+    }
+
+    /// Produces the conflict target of the table for `ON CONFLICT (...)`:
+    /// the primary key columns like `("id")` or `("a","b")`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// assert_eq!(User::conflict_target(), r#"("id")"#);
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if there is no primary key in the table.
+    fn conflict_target() -> String {
+        let columns = Self::COLUMNS
+            .iter()
+            .filter(|col| col.is_primary())
+            .map(|col| format!("{:?}", col.name()))
+            .collect::<Vec<_>>();
+        #[cfg(debug_assertions)]
+        if columns.is_empty() {
+            panic!("no primary key in table {}", Self::TABLE_NAME);
+        }
+        format!("({})", columns.join(","))
+    }
+
+    /// Produces `INSERT INTO {table} ("a","b") VALUES (?,?)` for the specified
+    /// columns only, so the others take their default values. Placeholders
+    /// follow the specified [`Dialect`]
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sql = User::insert_columns_sql(&[User::Name], Dialect::Sqlite);
+    /// assert_eq!(sql, r#"INSERT INTO "User" ("name") VALUES (?)"#);
+    /// sqlx::query(&sql).bind("Bob").execute(&pool).await?;
+    /// ```
+    ///
+    /// # Panic
+    ///
+    /// Panics if a column does not belong to the table.
+    fn insert_columns_sql(columns: &[SqlColumn], dialect: Dialect) -> String {
+        #[cfg(debug_assertions)]
+        if let Some(col) = columns
+            .iter()
+            .find(|col| col.table_name() != trim_quotes(Self::TABLE_NAME))
+        {
+            panic!("column {col} does not belong to table {}", Self::TABLE_NAME);
+        }
+        let names = columns
+            .iter()
+            .map(|col| format!("{:?}", col.name()))
+            .collect::<Vec<_>>()
+            .join(",");
+        let values = (1..=columns.len())
+            .map(|i| dialect.placeholder(i))
+            .collect::<Vec<_>>()
+            .join(",");
+        let sql = format!(
+            "INSERT INTO {} ({names}) VALUES ({values})",
+            Self::TABLE_NAME
+        );
+        crate::dialect::localize(&sql, dialect)
+    }
+
+    /// Produces `SELECT {col}, COUNT(*) FROM {table} GROUP BY {col}`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sql = User::count_group_by_sql(User::DataId);
+    /// let rows: Vec<(Option<i64>, i64)> = sqlx::query_as(&sql).fetch_all(&pool).await?;
+    /// ```
+    fn count_group_by_sql(col: SqlColumn) -> String {
+        Select::new()
+            .with_column(col)
+            .with_column(crate::count_star())
+            .with_table(Self::as_table())
+            .with_group(col)
+            .build()
+    }
+
+    /// Produces `CREATE [UNIQUE] INDEX "name" ON "Table" ("a","b")`
+    ///
+    /// If no name is specified, it is generated from the table and column
+    /// names: `idx_{table}_{a}_{b}`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let sql = User::create_index_sql(&[User::Name, User::Age], true, None);
+    /// assert_eq!(sql, r#"CREATE UNIQUE INDEX "idx_User_name_age" ON "User" ("name","age")"#);
+    /// sqlx::query(&sql).execute(&pool).await?;
+    /// ```
+    fn create_index_sql(columns: &[SqlColumn], unique: bool, name: Option<&str>) -> String {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => {
+                let mut name = format!("idx_{}", trim_quotes(Self::TABLE_NAME));
+                for col in columns {
+                    name.push('_');
+                    name += col.name();
+                }
+                name
+            }
+        };
+        let columns = columns
+            .iter()
+            .map(|col| format!("{:?}", col.name()))
+            .collect::<Vec<_>>()
+            .join(",");
+        let unique = if unique { "UNIQUE " } else { "" };
+        format!(
+            "CREATE {unique}INDEX {name:?} ON {} ({columns})",
+            Self::TABLE_NAME
+        )
+    }
 }
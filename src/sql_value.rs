@@ -4,17 +4,26 @@ pub trait SqlValue {
     fn to_sql(&self) -> String;
 }
 
+/// Produces a single-quoted SQL string literal, doubling the inner quotes
+pub(crate) fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+impl<T: SqlValue + ?Sized> SqlValue for &T {
+    fn to_sql(&self) -> String {
+        (**self).to_sql()
+    }
+}
+
 impl SqlValue for String {
     fn to_sql(&self) -> String {
-        // use debug trait to escape all quotes
-        format!("{self:?}")
+        quote_literal(self)
     }
 }
 
-impl SqlValue for &str {
+impl SqlValue for str {
     fn to_sql(&self) -> String {
-        // use debug trait to escape all quotes
-        format!("{self:?}")
+        quote_literal(self)
     }
 }
 
@@ -42,6 +51,30 @@ impl SqlValue for u64 {
     }
 }
 
+impl SqlValue for std::num::NonZeroI32 {
+    fn to_sql(&self) -> String {
+        self.get().to_sql()
+    }
+}
+
+impl SqlValue for std::num::NonZeroU32 {
+    fn to_sql(&self) -> String {
+        self.get().to_sql()
+    }
+}
+
+impl SqlValue for std::num::NonZeroI64 {
+    fn to_sql(&self) -> String {
+        self.get().to_sql()
+    }
+}
+
+impl SqlValue for std::num::NonZeroU64 {
+    fn to_sql(&self) -> String {
+        self.get().to_sql()
+    }
+}
+
 impl SqlValue for f64 {
     fn to_sql(&self) -> String {
         self.to_string()
@@ -59,7 +92,7 @@ impl SqlValue for time::Date {
         let y = self.year();
         let m = self.month() as u8;
         let d = self.day();
-        format!("\"{y:04}-{m:02}-{d:02}\"")
+        format!("'{y:04}-{m:02}-{d:02}'")
     }
 }
 
@@ -68,7 +101,7 @@ impl SqlValue for time::Time {
         let h = self.hour();
         let m = self.minute();
         let s = self.second();
-        format!("\"{h:02}:{m:02}:{s:02}\"")
+        format!("'{h:02}:{m:02}:{s:02}'")
     }
 }
 
@@ -80,6 +113,27 @@ impl SqlValue for time::PrimitiveDateTime {
         let h = self.hour();
         let mm = self.minute();
         let s = self.second();
-        format!("\"{y:04}-{m:02}-{d:02}T{h:02}:{mm:02}:{s:02}\"")
+        format!("'{y:04}-{m:02}-{d:02}T{h:02}:{mm:02}:{s:02}'")
     }
 }
+
+/// Implements `SqlValue` for tuples as row literals: `(a, b, ...)`, which
+/// is the same as [`values_row`](crate::values_row) produces
+macro_rules! impl_sql_value_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: SqlValue),+> SqlValue for ($($name,)+) {
+            fn to_sql(&self) -> String {
+                #[allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let values = [$($name.to_sql()),+];
+                format!("({})", values.join(", "))
+            }
+        }
+    };
+}
+
+impl_sql_value_for_tuple!(A, B);
+impl_sql_value_for_tuple!(A, B, C);
+impl_sql_value_for_tuple!(A, B, C, D);
+impl_sql_value_for_tuple!(A, B, C, D, E);
+impl_sql_value_for_tuple!(A, B, C, D, E, F);
@@ -0,0 +1,133 @@
+/// Bound of a window [`Frame`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u32),
+    CurrentRow,
+    Following(u32),
+    UnboundedFollowing,
+}
+
+impl std::fmt::Display for FrameBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnboundedPreceding => f.write_str("UNBOUNDED PRECEDING"),
+            Self::Preceding(n) => write!(f, "{n} PRECEDING"),
+            Self::CurrentRow => f.write_str("CURRENT ROW"),
+            Self::Following(n) => write!(f, "{n} FOLLOWING"),
+            Self::UnboundedFollowing => f.write_str("UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+/// Frame of a window: `ROWS|RANGE BETWEEN {start} AND {end}`
+///
+/// # Example
+///
+/// ```no_run
+/// // running total
+/// let frame = Frame::rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+/// assert_eq!(frame.to_string(), "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame {
+    range: bool,
+    start: FrameBound,
+    end: FrameBound,
+}
+
+impl std::fmt::Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.range { "RANGE" } else { "ROWS" };
+        write!(f, "{kind} BETWEEN {} AND {}", self.start, self.end)
+    }
+}
+
+impl Frame {
+    /// Creates a frame counted in rows: `ROWS BETWEEN {start} AND {end}`
+    pub const fn rows(start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            range: false,
+            start,
+            end,
+        }
+    }
+
+    /// Creates a frame counted in values of the ordering column:
+    /// `RANGE BETWEEN {start} AND {end}`
+    pub const fn range(start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            range: true,
+            start,
+            end,
+        }
+    }
+}
+
+/// Window specification: `OVER (PARTITION BY ... ORDER BY ... {frame})`,
+/// applied to an expression with [`Expr::over`](crate::Expr::over)
+///
+/// # Example
+///
+/// ```no_run
+/// let over = Over::new()
+///     .with_partition(Order::UserId)
+///     .with_order(Order::CreateDate.asc())
+///     .with_frame(Frame::rows(FrameBound::UnboundedPreceding, FrameBound::CurrentRow));
+/// let expr = Order::Total.sum().over(over);
+/// assert_eq!(
+///     expr.to_string(),
+///     r#"SUM("Order"."total") OVER (PARTITION BY "Order"."user_id" ORDER BY "Order"."create_date" ASC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"#
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Over {
+    partition_by: Vec<String>,
+    order_by: Vec<String>,
+    frame: Option<Frame>,
+}
+
+impl std::fmt::Display for Over {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if !self.partition_by.is_empty() {
+            parts.push(format!("PARTITION BY {}", self.partition_by.join(",")));
+        }
+        if !self.order_by.is_empty() {
+            parts.push(format!("ORDER BY {}", self.order_by.join(",")));
+        }
+        if let Some(frame) = self.frame {
+            parts.push(frame.to_string());
+        }
+        write!(f, "OVER ({})", parts.join(" "))
+    }
+}
+
+impl Over {
+    /// Create an empty window specification: `OVER ()`
+    pub const fn new() -> Self {
+        Self {
+            partition_by: vec![],
+            order_by: vec![],
+            frame: None,
+        }
+    }
+
+    /// Adds a column to partition the window
+    pub fn with_partition<P: Into<String>>(mut self, partition: P) -> Self {
+        self.partition_by.push(partition.into());
+        self
+    }
+
+    /// Adds a column to sort the rows inside the window
+    pub fn with_order<O: Into<String>>(mut self, order: O) -> Self {
+        self.order_by.push(order.into());
+        self
+    }
+
+    /// Sets the frame of the window
+    pub fn with_frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(dead_code, non_upper_case_globals)]
+
+use humble_orm::{SqlColumn, SqlTable};
+
+#[derive(Default)]
+pub struct User;
+
+impl User {
+    pub const Id: SqlColumn = unsafe { SqlColumn::new("\"id\"", "\"User\"", true) };
+    pub const Name: SqlColumn = unsafe { SqlColumn::new("\"name\"", "\"User\"", false) };
+    pub const Age: SqlColumn = unsafe { SqlColumn::new("\"age\"", "\"User\"", false) };
+    pub const DataId: SqlColumn = unsafe { SqlColumn::new("\"data_id\"", "\"User\"", false) };
+}
+
+impl SqlTable for User {
+    const TABLE_NAME: &'static str = "\"User\"";
+    const COLUMNS: &'static [SqlColumn] = &[User::Id, User::Name, User::Age, User::DataId];
+}
+
+#[derive(Default)]
+pub struct UserData;
+
+impl UserData {
+    pub const Id: SqlColumn = unsafe { SqlColumn::new("\"id\"", "\"UserData\"", true) };
+    pub const Bio: SqlColumn = unsafe { SqlColumn::new("\"bio\"", "\"UserData\"", false) };
+}
+
+impl SqlTable for UserData {
+    const TABLE_NAME: &'static str = "\"UserData\"";
+    const COLUMNS: &'static [SqlColumn] = &[UserData::Id, UserData::Bio];
+}
+
+#[derive(Default)]
+pub struct Order;
+
+impl Order {
+    pub const Id: SqlColumn = unsafe { SqlColumn::new("\"id\"", "\"Order\"", true) };
+    pub const UserId: SqlColumn = unsafe { SqlColumn::new("\"user_id\"", "\"Order\"", false) };
+}
+
+impl SqlTable for Order {
+    const TABLE_NAME: &'static str = "\"Order\"";
+    const COLUMNS: &'static [SqlColumn] = &[Order::Id, Order::UserId];
+}
+
+#[derive(Default)]
+pub struct Membership;
+
+impl Membership {
+    pub const UserId: SqlColumn = unsafe { SqlColumn::new("\"user_id\"", "\"Membership\"", true) };
+    pub const GroupId: SqlColumn =
+        unsafe { SqlColumn::new("\"group_id\"", "\"Membership\"", true) };
+    pub const Role: SqlColumn = unsafe { SqlColumn::new("\"role\"", "\"Membership\"", false) };
+}
+
+impl SqlTable for Membership {
+    const TABLE_NAME: &'static str = "\"Membership\"";
+    const COLUMNS: &'static [SqlColumn] =
+        &[Membership::UserId, Membership::GroupId, Membership::Role];
+}
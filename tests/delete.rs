@@ -0,0 +1,58 @@
+mod common;
+
+use common::{User, UserData};
+use humble_orm::{Delete, Dialect, NullsOrder, Select, SqlTable};
+
+#[test]
+fn build_for_renders_the_same_query_per_dialect() {
+    let delete = Delete::new()
+        .with_table(User::as_table())
+        .with_where_cond(User::Name.eq("Bob"))
+        .with_where_in(
+            User::DataId,
+            Select::new()
+                .with_column(UserData::Id)
+                .with_table(UserData::as_table()),
+        );
+    assert_eq!(
+        delete.clone().build_for(Dialect::Sqlite),
+        r#"DELETE FROM "User" WHERE ("User"."name" = 'Bob') AND ("User"."data_id" IN (SELECT "UserData"."id" FROM "UserData"))"#
+    );
+    assert_eq!(
+        delete.clone().build_for(Dialect::Postgres),
+        delete.clone().build_for(Dialect::Sqlite)
+    );
+    assert_eq!(
+        delete.build_for(Dialect::MySql),
+        "DELETE FROM `User` WHERE (`User`.`name` = 'Bob') AND (`User`.`data_id` IN (SELECT `UserData`.`id` FROM `UserData`))"
+    );
+}
+
+#[test]
+fn default_is_an_empty_delete() {
+    let sql = Delete::default().with_table(User::as_table()).build();
+    assert_eq!(sql, r#"DELETE FROM "User""#);
+}
+
+#[test]
+fn subquery_follows_the_dialect_of_the_delete() {
+    let delete = Delete::new()
+        .with_table(User::as_table())
+        .with_where_not_in(
+            User::DataId,
+            Select::new()
+                .with_column(UserData::Id)
+                .with_table(UserData::as_table())
+                .with_order(UserData::Id.asc())
+                .with_nulls_order(NullsOrder::Largest)
+                .with_limit_offset(5),
+        );
+    assert_eq!(
+        delete.clone().build_for(Dialect::Postgres),
+        r#"DELETE FROM "User" WHERE ("User"."data_id" NOT IN (SELECT "UserData"."id" FROM "UserData" ORDER BY "UserData"."id" ASC NULLS LAST OFFSET 5))"#
+    );
+    assert_eq!(
+        delete.build_for(Dialect::MySql),
+        "DELETE FROM `User` WHERE (`User`.`data_id` NOT IN (SELECT `UserData`.`id` FROM `UserData` ORDER BY `UserData`.`id` IS NULL ASC, `UserData`.`id` ASC LIMIT 18446744073709551615 OFFSET 5))"
+    );
+}
@@ -0,0 +1,22 @@
+mod common;
+
+use std::error::Error;
+
+use common::User;
+use humble_orm::HumbleError;
+
+#[test]
+fn error_names_the_table_and_column() {
+    let err = HumbleError::new::<User, _>("bad").with_column("age");
+    assert_eq!(err.to_string(), "table `User`, column `age`: bad");
+    assert_eq!(err.table(), "User");
+    assert_eq!(err.column(), Some("age"));
+    assert_eq!(err.source().unwrap().to_string(), "bad");
+}
+
+#[test]
+fn error_without_column_names_the_table() {
+    let err = HumbleError::new::<User, _>("bad");
+    assert_eq!(err.to_string(), "table `User`: bad");
+    assert_eq!(err.column(), None);
+}
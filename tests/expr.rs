@@ -0,0 +1,73 @@
+mod common;
+
+use common::User;
+use humble_orm::{Select, SqlTable, coalesce, count_star, count_star_as};
+
+#[test]
+fn aggregates_compare_with_aggregates() {
+    assert_eq!(
+        User::Id.count().gt(User::Age.sum()),
+        r#"COUNT("User"."id") > SUM("User"."age")"#
+    );
+    assert_eq!(
+        User::Age.max().le(User::Age.avg()),
+        r#"MAX("User"."age") <= AVG("User"."age")"#
+    );
+}
+
+#[test]
+fn having_compares_two_aggregates() {
+    let sql = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_group(User::Name)
+        .with_having(User::Id.count().gt(User::Age.sum()))
+        .build();
+    assert_eq!(
+        sql,
+        r#"SELECT "User"."name" FROM "User" GROUP BY "User"."name" HAVING (COUNT("User"."id") > SUM("User"."age"))"#
+    );
+}
+
+#[test]
+fn count_star_and_count_column_are_distinct() {
+    assert_eq!(count_star().to_string(), "COUNT(*)");
+    assert_eq!(count_star_as("total").to_string(), r#"COUNT(*) AS "total""#);
+    assert_eq!(
+        User::DataId.count_nonnull().to_string(),
+        r#"COUNT("User"."data_id")"#
+    );
+    assert_eq!(
+        User::DataId.count_nonnull().to_string(),
+        User::DataId.count().to_string()
+    );
+}
+
+#[test]
+fn compound_expressions_are_parenthesized_on_the_right() {
+    assert_eq!(
+        User::Age.gt(User::Id * 2),
+        r#""User"."age" > ("User"."id" * 2)"#
+    );
+    assert_eq!(
+        User::Age.le((User::Id + 1) * 2),
+        r#""User"."age" <= (("User"."id" + 1) * 2)"#
+    );
+    assert_eq!(
+        User::Age.eq(User::Age.sum() / User::Id.count()),
+        r#""User"."age" = (SUM("User"."age") / COUNT("User"."id"))"#
+    );
+}
+
+#[test]
+fn order_by_coalesced_expression_descending() {
+    let sql = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_order(coalesce([User::Age, User::Id]).desc())
+        .build();
+    assert_eq!(
+        sql,
+        r#"SELECT "User"."name" FROM "User" ORDER BY COALESCE("User"."age", "User"."id") DESC"#
+    );
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "sqlx")]
+#![allow(non_upper_case_globals)]
+
+use humble_orm::{SqlColumn, SqlTable, from_sql};
+use sqlx::Row;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+
+#[derive(Debug, Default, PartialEq)]
+struct User {
+    id: i64,
+    name: String,
+}
+
+impl User {
+    const Id: SqlColumn = unsafe { SqlColumn::new("\"id\"", "\"User\"", true) };
+    const Name: SqlColumn = unsafe { SqlColumn::new("\"name\"", "\"User\"", false) };
+}
+
+impl SqlTable for User {
+    const TABLE_NAME: &'static str = "\"User\"";
+    const COLUMNS: &'static [SqlColumn] = &[User::Id, User::Name];
+}
+
+impl<'r> sqlx::FromRow<'r, SqliteRow> for User {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+        })
+    }
+}
+
+async fn pool() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query(r#"CREATE TABLE "User" ("id" INTEGER PRIMARY KEY, "name" TEXT NOT NULL)"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(r#"INSERT INTO "User" VALUES (1, 'Bob'), (2, 'Jack')"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+#[tokio::test]
+async fn fetch_all_maps_rows() {
+    let pool = pool().await;
+    let users: Vec<User> = User::query()
+        .with_order(User::Id.asc())
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        users,
+        [
+            User {
+                id: 1,
+                name: String::from("Bob")
+            },
+            User {
+                id: 2,
+                name: String::from("Jack")
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn fetch_optional_maps_a_row() {
+    let pool = pool().await;
+    let user: Option<User> = User::query()
+        .with_where_cond(User::Name.eq("Jack"))
+        .fetch_optional(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        user,
+        Some(User {
+            id: 2,
+            name: String::from("Jack")
+        })
+    );
+    let user: Option<User> = User::query()
+        .with_where_cond(User::Id.eq(3))
+        .fetch_optional(&pool)
+        .await
+        .unwrap();
+    assert_eq!(user, None);
+}
+
+#[tokio::test]
+async fn from_sql_maps_a_hand_written_query() {
+    let pool = pool().await;
+    let users: Vec<User> = from_sql(&pool, r#"SELECT * FROM "User" WHERE "id" % 2 = 0"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        users,
+        [User {
+            id: 2,
+            name: String::from("Jack")
+        }]
+    );
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::User;
+use humble_orm::{
+    Dialect, Select, SqlTable, SqlValue, count_distinct_multi, json_object, json_object_as,
+    keyset_after, random_order, row, values_row,
+};
+
+#[test]
+fn json_object_renders_for_the_specified_dialect() {
+    assert_eq!(
+        json_object([("id", User::Id)], Dialect::Sqlite),
+        r#"json_object('id', "User"."id")"#
+    );
+    assert_eq!(
+        json_object([("id", User::Id)], Dialect::Postgres),
+        r#"json_build_object('id', "User"."id")"#
+    );
+}
+
+#[test]
+fn random_order_renders_for_the_specified_dialect() {
+    assert_eq!(random_order(Dialect::default()), "RANDOM()");
+    assert_eq!(random_order(Dialect::Postgres), "RANDOM()");
+    assert_eq!(random_order(Dialect::MySql), "RAND()");
+}
+
+#[test]
+fn json_object_as_builds_a_two_field_projection() {
+    let sql = Select::new()
+        .with_column(json_object_as(
+            "user",
+            [("id", User::Id), ("it's", User::Name)],
+            Dialect::Postgres,
+        ))
+        .with_table(User::as_table())
+        .build_for(Dialect::Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT json_build_object('id', "User"."id", 'it''s', "User"."name") AS "user" FROM "User""#
+    );
+}
+
+#[test]
+fn keyset_after_compares_rows() {
+    let cond = keyset_after(&[User::Age, User::Id], &[&30, &17]);
+    assert_eq!(cond, r#"("User"."age", "User"."id") > (30, 17)"#);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn keyset_after_rejects_mismatched_lengths() {
+    keyset_after(&[User::Age, User::Id], &[&30]);
+}
+
+#[test]
+#[should_panic(expected = "must not be empty")]
+fn keyset_after_rejects_empty_keys() {
+    keyset_after(&[], &[]);
+}
+
+#[test]
+fn count_distinct_multi_renders_for_the_specified_dialect() {
+    assert_eq!(
+        count_distinct_multi([User::Name, User::Age], Dialect::Postgres).to_string(),
+        r#"COUNT(DISTINCT ("User"."name", "User"."age"))"#
+    );
+    assert_eq!(
+        count_distinct_multi([User::Name, User::Age], Dialect::MySql).to_string(),
+        r#"COUNT(DISTINCT "User"."name", "User"."age")"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "SQLite")]
+fn count_distinct_multi_rejects_sqlite() {
+    count_distinct_multi([User::Name, User::Age], Dialect::Sqlite);
+}
+
+#[test]
+fn values_row_and_row_construct_rows() {
+    let values = [&1 as &dyn SqlValue, &"it's", &true];
+    assert_eq!(values_row(values), "(1, 'it''s', true)");
+    assert_eq!(row(values), "ROW(1, 'it''s', true)");
+    assert_eq!(values_row([2.5]), "(2.5)");
+}
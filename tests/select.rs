@@ -0,0 +1,381 @@
+mod common;
+
+use common::{Order, User, UserData};
+use humble_orm::{Dialect, NullsOrder, Select, SelectError, SqlTable, count_star_as};
+
+#[test]
+fn nulls_order_is_applied_per_dialect() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_order(User::Age.desc())
+        .with_nulls_order(NullsOrder::Smallest);
+    assert_eq!(
+        select.clone().build_for(Dialect::Postgres),
+        r#"SELECT "User"."name" FROM "User" ORDER BY "User"."age" DESC NULLS LAST"#
+    );
+    assert_eq!(
+        select.build_for(Dialect::MySql),
+        "SELECT `User`.`name` FROM `User` ORDER BY `User`.`age` IS NULL ASC, `User`.`age` DESC"
+    );
+}
+
+#[test]
+fn nulls_order_is_unspecified_by_default() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_order(User::Age.asc())
+        .with_order("random()");
+    assert_eq!(
+        select.clone().build_for(Dialect::Sqlite),
+        select.build_for(Dialect::Postgres)
+    );
+}
+
+#[test]
+fn build_for_renders_the_same_query_per_dialect() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_where_cond(User::Name.eq(r"O'Neil\"))
+        .with_where_cond(User::Id.ne(false))
+        .with_order(User::Age.asc())
+        .with_nulls_order(NullsOrder::Largest)
+        .with_limit_offset(20);
+    assert_eq!(
+        select.clone().build_for(Dialect::Sqlite),
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."name" = 'O''Neil\') AND ("User"."id" != 0) ORDER BY "User"."age" ASC NULLS LAST LIMIT -1 OFFSET 20"#
+    );
+    assert_eq!(
+        select.clone().build_for(Dialect::Postgres),
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."name" = 'O''Neil\') AND ("User"."id" != FALSE) ORDER BY "User"."age" ASC NULLS LAST OFFSET 20"#
+    );
+    assert_eq!(
+        select.build_for(Dialect::MySql),
+        r"SELECT `User`.`name` FROM `User` WHERE (`User`.`name` = 'O''Neil\\') AND (`User`.`id` != FALSE) ORDER BY `User`.`age` IS NULL ASC, `User`.`age` ASC LIMIT 18446744073709551615 OFFSET 20"
+    );
+}
+
+#[test]
+fn subquery_is_adapted_with_the_outer_query() {
+    let sub = Select::new()
+        .with_column(UserData::Id)
+        .with_table(UserData::as_table())
+        .with_where_cond(UserData::Bio.is_not_null());
+    let sql = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_where_cond(format!(
+            "{} IN {}",
+            User::DataId,
+            sub.parenthesized(Dialect::MySql)
+        ))
+        .build_for(Dialect::MySql);
+    assert_eq!(
+        sql,
+        "SELECT `User`.`name` FROM `User` WHERE (`User`.`data_id` IN (SELECT `UserData`.`id` FROM `UserData` WHERE (`UserData`.`bio` IS NOT NULL)))"
+    );
+}
+
+#[test]
+fn try_build_detects_aliased_aggregates() {
+    let aggregates = [
+        User::Id.count_as("n"),
+        User::Age.sum_as("n"),
+        User::Age.avg_as("n"),
+        User::Age.min_as("n"),
+        User::Age.max_as("n"),
+        count_star_as("n"),
+        User::Age.max().alias("n"),
+    ];
+    for aggregate in aggregates {
+        let result = Select::new()
+            .with_column(User::Name.alias("name"))
+            .with_column(aggregate.clone())
+            .with_table(User::as_table())
+            .try_build();
+        assert!(
+            matches!(result, Err(SelectError::MissingGroupBy { ref column }) if column == r#""User"."name""#),
+            "{aggregate}"
+        );
+        let result = Select::new()
+            .with_column(User::Name.alias("name"))
+            .with_column(aggregate)
+            .with_table(User::as_table())
+            .with_group(User::Name)
+            .try_build();
+        assert!(result.is_ok());
+    }
+}
+
+#[test]
+fn try_build_accepts_aliased_plain_expressions() {
+    let result = Select::new()
+        .with_column(User::Name)
+        .with_column((User::Age + 1).alias("next_age"))
+        .with_table(User::as_table())
+        .try_build();
+    assert_eq!(
+        result.unwrap(),
+        r#"SELECT "User"."name",("User"."age" + 1) AS "next_age" FROM "User""#
+    );
+}
+
+#[test]
+fn build_prepared_binds_limit_and_offset() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_where_cond(User::Age.gt(30))
+        .with_limit(10)
+        .with_limit_offset(20);
+    assert_eq!(
+        select.clone().build_prepared_for(Dialect::Postgres),
+        (
+            String::from(
+                r#"SELECT "User"."name" FROM "User" WHERE ("User"."age" > 30) LIMIT $1 OFFSET $2"#
+            ),
+            vec![10, 20]
+        )
+    );
+    assert_eq!(
+        select.build(),
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."age" > 30) LIMIT 10 OFFSET 20"#
+    );
+}
+
+#[test]
+fn append_merges_clauses_and_keeps_own_limit() {
+    let mut base = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_where_cond(User::Age.gt(18))
+        .with_limit(10);
+    let filters = Select::new()
+        .with_column(User::Id)
+        .with_table(UserData::as_table())
+        .with_where_cond(User::Name.like("J%"))
+        .with_group(User::Name)
+        .with_having(User::Id.count().gt(1))
+        .with_order(User::Name.asc())
+        .with_limit(50)
+        .with_limit_offset(5);
+    base.append(&filters);
+    assert_eq!(
+        base.build(),
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."age" > 18) AND ("User"."name" LIKE 'J%') GROUP BY "User"."name" HAVING (COUNT("User"."id") > 1) ORDER BY "User"."name" ASC LIMIT 10 OFFSET 5"#
+    );
+}
+
+#[test]
+fn sample_follows_the_table() {
+    let sql = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_sample(12.5)
+        .with_where_cond(User::Age.gt(18))
+        .build_for(Dialect::Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT "User"."name" FROM "User" TABLESAMPLE SYSTEM (12.5) WHERE ("User"."age" > 18)"#
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "0..=100")]
+fn sample_rejects_percent_out_of_range() {
+    Select::new().with_sample(150.0);
+}
+
+#[test]
+fn referenced_tables_lists_joined_tables() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_join(UserData::as_table(), [User::DataId.eq(UserData::Id)])
+        .with_left_join(Order::as_table(), [Order::UserId.eq(User::Id)]);
+    assert_eq!(select.referenced_tables(), ["User", "UserData", "Order"]);
+    assert_eq!(
+        select.build(),
+        r#"SELECT "User"."name" FROM "User" INNER JOIN "UserData" ON ("User"."data_id" = "UserData"."id") LEFT JOIN "Order" ON ("Order"."user_id" = "User"."id")"#
+    );
+}
+
+#[test]
+fn columns_except_skips_the_named_columns() {
+    let sql = Select::new()
+        .with_columns_except::<User>(&["data_id"])
+        .with_table(User::as_table())
+        .build();
+    assert_eq!(
+        sql,
+        r#"SELECT "User"."id","User"."name","User"."age" FROM "User""#
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "missing")]
+fn columns_except_rejects_unknown_names() {
+    Select::new().with_columns_except::<User>(&["missing"]);
+}
+
+#[test]
+fn try_build_detects_cartesian_products() {
+    let result = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_join(UserData::as_table(), Vec::<String>::new())
+        .try_build();
+    assert!(matches!(
+        result,
+        Err(SelectError::PossibleCartesianProduct { table: "UserData" })
+    ));
+
+    let result = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_left_join(UserData::as_table(), Vec::<String>::new())
+        .try_build();
+    assert!(matches!(
+        result,
+        Err(SelectError::PossibleCartesianProduct { table: "UserData" })
+    ));
+
+    let result = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_cross_join(UserData::as_table())
+        .try_build();
+    assert_eq!(
+        result.unwrap(),
+        r#"SELECT "User"."name" FROM "User" CROSS JOIN "UserData""#
+    );
+}
+
+#[test]
+fn to_sql_debug_inlines_what_build_prepared_binds() {
+    let select = Select::new()
+        .with_column(User::Name)
+        .with_table(User::as_table())
+        .with_where_cond(User::Name.eq("Bob"))
+        .with_limit(10)
+        .with_limit_offset(20);
+    let debug = select.to_sql_debug();
+    assert_eq!(debug, select.clone().build());
+    assert_eq!(
+        debug,
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."name" = 'Bob') LIMIT 10 OFFSET 20"#
+    );
+    let (sql, args) = select.build_prepared();
+    assert_eq!(
+        sql,
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."name" = 'Bob') LIMIT ? OFFSET ?"#
+    );
+    assert_eq!(args, [10, 20]);
+}
+
+#[test]
+fn frozen_select_is_shared_and_builds_like_the_original() {
+    let select = Select::new()
+        .with_columns([User::Id, User::Name])
+        .with_table(User::as_table())
+        .with_limit(5);
+    let expected = select.clone().build();
+    let expected_prepared = select.clone().build_prepared();
+    let frozen = select.freeze();
+    let copy = frozen.clone();
+    assert!(frozen.ptr_eq(&copy));
+    assert!(!frozen.ptr_eq(&frozen.thaw().freeze()));
+    assert_eq!(copy.build(), expected);
+    assert_eq!(copy.build_prepared(), expected_prepared);
+    assert_eq!(
+        std::thread::spawn(move || copy.build_for(Dialect::MySql))
+            .join()
+            .unwrap(),
+        "SELECT `User`.`id`,`User`.`name` FROM `User` LIMIT 5"
+    );
+    assert_eq!(
+        frozen.thaw().with_where_cond(User::Id.gt(1)).build(),
+        r#"SELECT "User"."id","User"."name" FROM "User" WHERE ("User"."id" > 1) LIMIT 5"#
+    );
+}
+
+#[test]
+fn try_build_detects_columns_of_unselected_tables() {
+    let result = Select::new()
+        .with_columns([User::Name, UserData::Bio])
+        .with_table(User::as_table())
+        .try_build();
+    assert!(matches!(
+        result,
+        Err(SelectError::UnknownColumn { ref column, table: "UserData" }) if column == r#""UserData"."bio""#
+    ));
+
+    let result = Select::new()
+        .with_columns([User::Name, UserData::Bio])
+        .with_table(User::as_table())
+        .with_left_join(UserData::as_table(), [User::DataId.eq(UserData::Id)])
+        .try_build();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn subquery_follows_the_dialect_of_the_outer_query() {
+    let sub = || {
+        Select::new()
+            .with_column(UserData::Id)
+            .with_table(UserData::as_table())
+            .with_order(UserData::Id.asc())
+            .with_nulls_order(NullsOrder::Largest)
+            .with_limit_offset(5)
+    };
+    let outer = |dialect| {
+        Select::new()
+            .with_column(User::Name)
+            .with_table(User::as_table())
+            .with_where_cond(format!(
+                "{} IN {}",
+                User::DataId,
+                sub().parenthesized(dialect)
+            ))
+            .build_for(dialect)
+    };
+    assert_eq!(
+        outer(Dialect::Postgres),
+        r#"SELECT "User"."name" FROM "User" WHERE ("User"."data_id" IN (SELECT "UserData"."id" FROM "UserData" ORDER BY "UserData"."id" ASC NULLS LAST OFFSET 5))"#
+    );
+    assert_eq!(
+        outer(Dialect::MySql),
+        "SELECT `User`.`name` FROM `User` WHERE (`User`.`data_id` IN (SELECT `UserData`.`id` FROM `UserData` ORDER BY `UserData`.`id` IS NULL ASC, `UserData`.`id` ASC LIMIT 18446744073709551615 OFFSET 5))"
+    );
+}
+
+#[test]
+fn dedup_columns_keeps_the_first_occurrence() {
+    let mut select = Select::new();
+    select.push_columns([User::Id, User::Name]);
+    select.push_column(User::Id);
+    select.dedup_columns();
+    select.set_table(User::as_table());
+    assert_eq!(
+        select.build(),
+        r#"SELECT "User"."id","User"."name" FROM "User""#
+    );
+}
+
+#[test]
+fn table_only_excludes_inherited_tables() {
+    let sql = Select::new()
+        .with_column(User::Name)
+        .with_table_only(User::as_table())
+        .build_for(Dialect::Postgres);
+    assert_eq!(sql, r#"SELECT "User"."name" FROM ONLY "User""#);
+
+    let mut select = Select::new();
+    select.push_column(User::Name);
+    select.set_table_only(User::as_table());
+    assert_eq!(select.build(), r#"SELECT "User"."name" FROM ONLY "User""#);
+}
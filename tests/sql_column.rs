@@ -0,0 +1,140 @@
+mod common;
+
+use common::User;
+use humble_orm::{Dialect, SqlColumn};
+
+#[test]
+fn name_and_table_name_trim_quotes() {
+    let col = unsafe { SqlColumn::new("\"id\"", "\"User\"", true) };
+    assert_eq!(col.name(), "id");
+    assert_eq!(col.table_name(), "User");
+}
+
+#[test]
+fn matches_fts_renders_for_the_specified_dialect() {
+    assert_eq!(
+        User::Name.matches_fts("it's", Dialect::Sqlite),
+        r#""User"."name" MATCH 'it''s'"#
+    );
+    assert_eq!(
+        User::Name.matches_fts("it's", Dialect::Postgres),
+        r#"to_tsvector("User"."name") @@ plainto_tsquery('it''s')"#
+    );
+    assert_eq!(
+        User::Name.matches_fts("it's", Dialect::MySql),
+        r#"MATCH ("User"."name") AGAINST ('it''s')"#
+    );
+}
+
+#[test]
+fn in_values_table_renders_a_derived_table() {
+    assert_eq!(
+        User::Id.in_values_table([1, 2], Dialect::Sqlite),
+        r#""User"."id" IN (SELECT column1 FROM (VALUES (1),(2)))"#
+    );
+    assert_eq!(
+        User::Id.in_values_table([1, 2], Dialect::Postgres),
+        r#""User"."id" IN (SELECT v FROM (VALUES (1),(2)) AS t(v))"#
+    );
+    assert_eq!(
+        User::Id.in_values_table([1, 2], Dialect::MySql),
+        r#""User"."id" IN (SELECT v FROM (VALUES ROW(1),ROW(2)) AS t(v))"#
+    );
+    assert_eq!(
+        User::Id.in_values_table(Vec::<i32>::new(), Dialect::Postgres),
+        "false"
+    );
+}
+
+#[test]
+fn comment_is_read_back() {
+    let col = unsafe { SqlColumn::new("\"email\"", "\"User\"", false) }
+        .with_comment("Primary contact address");
+    assert_eq!(col.comment(), Some("Primary contact address"));
+    assert_eq!(User::Name.comment(), None);
+}
+
+#[test]
+fn eq_any_of_joins_with_or() {
+    assert_eq!(
+        User::Name.eq_any_of(["Bob", "Jack"]),
+        r#"("User"."name" = 'Bob') OR ("User"."name" = 'Jack')"#
+    );
+    assert_eq!(User::Name.eq_any_of(Vec::<&str>::new()), "false");
+}
+
+#[test]
+fn like_pattern_escapes_wildcards_and_quotes() {
+    assert_eq!(
+        User::Name.like_pattern("100!%%", '!'),
+        r#""User"."name" LIKE '100!%%' ESCAPE '!'"#
+    );
+    assert_eq!(
+        User::Name.like_pattern("it's 50\\%%", '\\'),
+        r#""User"."name" LIKE 'it''s 50\%%' ESCAPE '\'"#
+    );
+    assert_eq!(
+        User::Name.like_pattern("a'_%", '\''),
+        r#""User"."name" LIKE 'a''_%' ESCAPE ''''"#
+    );
+}
+
+#[test]
+fn in_range_is_half_open() {
+    assert_eq!(
+        User::Age.in_range(18, 30),
+        r#"("User"."age" >= 18) AND ("User"."age" < 30)"#
+    );
+}
+
+#[test]
+fn lower_eq_and_lower_like_quote_the_value() {
+    assert_eq!(
+        User::Name.lower_eq("O'Neil"),
+        r#"LOWER("User"."name") = LOWER('O''Neil')"#
+    );
+    assert_eq!(
+        User::Name.lower_like("o'n%"),
+        r#"LOWER("User"."name") LIKE LOWER('o''n%')"#
+    );
+}
+
+#[test]
+fn json_conditions_quote_the_document_and_key() {
+    assert_eq!(
+        User::Name.json_contains(r#"{"tag": "it's"}"#),
+        r#""User"."name" @> '{"tag": "it''s"}'::jsonb"#
+    );
+    assert_eq!(
+        User::Name.json_has_key("user's"),
+        r#""User"."name" ? 'user''s'"#
+    );
+}
+
+#[test]
+fn eq_null_safe_renders_for_the_specified_dialect() {
+    assert_eq!(
+        User::Age.eq_null_safe(30, Dialect::Postgres),
+        r#""User"."age" IS NOT DISTINCT FROM 30"#
+    );
+    assert_eq!(
+        User::Age.eq_null_safe(30, Dialect::MySql),
+        r#""User"."age" <=> 30"#
+    );
+}
+
+#[test]
+fn modulo_renders_for_the_specified_dialect() {
+    assert_eq!(
+        User::Id.modulo(2, Dialect::Sqlite).eq(0),
+        r#"("User"."id" % 2) = 0"#
+    );
+    assert_eq!(
+        User::Id.modulo(2, Dialect::Postgres).to_string(),
+        r#"MOD("User"."id", 2)"#
+    );
+    assert_eq!(
+        User::Id.mod_fn(2).to_string(),
+        User::Id.modulo(2, Dialect::MySql).to_string()
+    );
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::User;
+use humble_orm::{Dialect, IntervalUnit, SqlInterval, SqlValue};
+
+#[test]
+fn to_sql_uses_the_standard_form() {
+    assert_eq!(SqlInterval::days(7).to_sql(), "INTERVAL '7' DAY");
+    assert_eq!(
+        SqlInterval::new(3, IntervalUnit::Month).to_sql(),
+        "INTERVAL '3' MONTH"
+    );
+}
+
+#[test]
+fn within_last_uses_a_date_modifier_for_sqlite() {
+    assert_eq!(
+        User::Age.within_last(SqlInterval::hours(12), Dialect::Sqlite),
+        r#""User"."age" >= datetime('now', '-12 hours')"#
+    );
+    assert_eq!(
+        User::Age.within_last(SqlInterval::hours(12), Dialect::MySql),
+        r#""User"."age" >= CURRENT_TIMESTAMP - INTERVAL '12' HOUR"#
+    );
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{Membership, User, UserData};
+use humble_orm::{Dialect, SqlTable};
+
+#[test]
+fn query_selects_all_columns_of_the_table() {
+    assert_eq!(
+        User::query().build(),
+        r#"SELECT "User"."id","User"."name","User"."age","User"."data_id" FROM "User""#
+    );
+    assert_eq!(
+        User::query().with_where_cond(User::Id.eq(1)).build(),
+        r#"SELECT "User"."id","User"."name","User"."age","User"."data_id" FROM "User" WHERE ("User"."id" = 1)"#
+    );
+}
+
+#[test]
+fn insert_columns_sql_is_rendered_for_the_specified_dialect() {
+    assert_eq!(
+        User::insert_columns_sql(&[User::Name, User::Age], Dialect::Postgres),
+        r#"INSERT INTO "User" ("name","age") VALUES ($1,$2)"#
+    );
+    assert_eq!(
+        User::insert_columns_sql(&[User::Name, User::Age], Dialect::MySql),
+        "INSERT INTO `User` (`name`,`age`) VALUES (?,?)"
+    );
+}
+
+#[test]
+fn conflict_target_lists_primary_key_columns() {
+    assert_eq!(User::conflict_target(), r#"("id")"#);
+    assert_eq!(Membership::conflict_target(), r#"("user_id","group_id")"#);
+}
+
+#[test]
+fn create_index_sql_names_the_index() {
+    assert_eq!(
+        User::create_index_sql(&[User::Name, User::Age], true, None),
+        r#"CREATE UNIQUE INDEX "idx_User_name_age" ON "User" ("name","age")"#
+    );
+    assert_eq!(
+        User::create_index_sql(&[User::Age], false, Some("user_age")),
+        r#"CREATE INDEX "user_age" ON "User" ("age")"#
+    );
+}
+
+#[test]
+fn insert_columns_sql_uses_default_dialect_placeholders() {
+    assert_eq!(
+        User::insert_columns_sql(&[User::Name], Dialect::default()),
+        r#"INSERT INTO "User" ("name") VALUES (?)"#
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "does not belong")]
+fn insert_columns_sql_rejects_foreign_columns() {
+    User::insert_columns_sql(&[User::Name, UserData::Bio], Dialect::default());
+}
+
+#[test]
+fn count_group_by_sql_counts_rows_per_value() {
+    assert_eq!(
+        User::count_group_by_sql(User::DataId),
+        r#"SELECT "User"."data_id",COUNT(*) FROM "User" GROUP BY "User"."data_id""#
+    );
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use std::num::{NonZeroI32, NonZeroU64};
+
+use common::User;
+use humble_orm::{SqlValue, values_row};
+
+#[test]
+fn tuples_render_as_row_literals() {
+    assert_eq!((1, "x").to_sql(), "(1, 'x')");
+    assert_eq!((1, "it's", true).to_sql(), "(1, 'it''s', true)");
+    assert_eq!(
+        values_row([&1 as &dyn SqlValue, &"it's", &true]),
+        (1, "it's", true).to_sql()
+    );
+}
+
+#[test]
+fn tuples_work_in_row_lists() {
+    assert_eq!(
+        User::Id.in_list([(1, "x"), (2, "y")]),
+        r#""User"."id" IN ((1, 'x'),(2, 'y'))"#
+    );
+}
+
+#[test]
+fn references_render_like_their_values() {
+    let name = String::from("Bob");
+    let age: &i32 = &30;
+    let key: &&str = &"it's";
+    assert_eq!(User::Name.eq(&name), r#""User"."name" = 'Bob'"#);
+    assert_eq!(User::Age.eq(age), r#""User"."age" = 30"#);
+    assert_eq!(User::Name.eq(key), r#""User"."name" = 'it''s'"#);
+    assert_eq!(User::Name.eq(*key), r#""User"."name" = 'it''s'"#);
+}
+
+#[test]
+fn non_zero_integers_render_as_numbers() {
+    let id = NonZeroU64::new(42).unwrap();
+    let delta = NonZeroI32::new(-7).unwrap();
+    assert_eq!(id.to_sql(), "42");
+    assert_eq!(delta.to_sql(), "-7");
+    assert_eq!(User::Id.eq(id), r#""User"."id" = 42"#);
+}
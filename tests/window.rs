@@ -0,0 +1,35 @@
+mod common;
+
+use common::{Order, User};
+use humble_orm::{Frame, FrameBound, Over};
+
+#[test]
+fn window_renders_an_explicit_frame() {
+    let over = Over::new()
+        .with_partition(Order::UserId)
+        .with_order(Order::Id.asc())
+        .with_frame(Frame::rows(
+            FrameBound::UnboundedPreceding,
+            FrameBound::CurrentRow,
+        ));
+    assert_eq!(
+        Order::Id.count().over(over).to_string(),
+        r#"COUNT("Order"."id") OVER (PARTITION BY "Order"."user_id" ORDER BY "Order"."id" ASC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"#
+    );
+}
+
+#[test]
+fn frame_renders_bounded_ranges() {
+    let frame = Frame::range(FrameBound::Preceding(3), FrameBound::Following(1));
+    assert_eq!(
+        frame.to_string(),
+        "RANGE BETWEEN 3 PRECEDING AND 1 FOLLOWING"
+    );
+    assert_eq!(
+        User::Age
+            .avg()
+            .over(Over::new().with_frame(frame))
+            .to_string(),
+        r#"AVG("User"."age") OVER (RANGE BETWEEN 3 PRECEDING AND 1 FOLLOWING)"#
+    );
+}